@@ -61,7 +61,7 @@ fn bench_mul_div<S: ScaleMetrics>(c: &mut Criterion, label: &str) {
                     );
                     // Keep the product alive via a trivial accumulation with addition.
                     let prod = black_box(xi) * black_box(yi);
-                    sink = sink + prod;
+                    sink += prod;
                 }
                 black_box(sink)
             })
@@ -85,7 +85,7 @@ fn bench_mul_div<S: ScaleMetrics>(c: &mut Criterion, label: &str) {
                         DecimalU64::<S>::from_raw(y[i].unscaled()),
                     );
                     let q = black_box(xi) / black_box(yi);
-                    sink = sink + q;
+                    sink += q;
                 }
                 black_box(sink)
             })
@@ -34,7 +34,7 @@ fn rescale_benches(c: &mut Criterion) {
                         x.is_negative(),
                         DecimalU64::<U8>::from_raw(x.unscaled()),
                     );
-                    acc = acc + black_box(vx).to_scale::<U2>(RoundingMode::HalfEven);
+                    acc += black_box(vx).to_scale::<U2>(RoundingMode::HalfEven);
                 }
                 black_box(acc)
             })
@@ -64,7 +64,7 @@ fn rescale_benches(c: &mut Criterion) {
                     x.is_negative(),
                     DecimalU64::<U2>::from_raw(x.unscaled()),
                 );
-                acc = acc + black_box(vx).to_scale::<U8>(RoundingMode::TowardZero);
+                acc += black_box(vx).to_scale::<U8>(RoundingMode::TowardZero);
             }
             black_box(acc)
         })
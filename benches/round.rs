@@ -39,7 +39,7 @@ fn bench_round<S: ScaleMetrics>(c: &mut Criterion, label: &str) {
                         DecimalU64::<S>::from_raw(x.unscaled()),
                     );
                     let y = black_box(vx).round_dp(dp, *mode);
-                    sum = sum + y;
+                    sum += y;
                 }
                 black_box(sum)
             })
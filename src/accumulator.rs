@@ -0,0 +1,131 @@
+//! Exact wide summation for `SignedDecimalU64<S>`.
+//!
+//! `SignedDecimalAccumulator<S>` keeps the running total as a signed unscaled
+//! `i128`, so intermediate totals may exceed the `u64` magnitude range as long
+//! as the final result fits. Only `finish()` can fail.
+
+use core::iter::{FromIterator, Sum};
+use core::marker::PhantomData;
+use decimal64::ScaleMetrics;
+
+use crate::arithmetic::deref_copy;
+use crate::error::{MathError, Result};
+use crate::SignedDecimalU64;
+
+/// An exact running total of `SignedDecimalU64<S>` values.
+///
+/// Every value adds less than `2^64` to the total, so fewer than `2^63` pushes
+/// can never leave the `i128` range. The total is kept with wrapping
+/// arithmetic, which makes it exact regardless of the order of pushes and
+/// merges whenever the true total fits in `i128`.
+///
+/// ```rust
+/// # use signed_decimal64::{accumulator::SignedDecimalAccumulator, SignedDecimalU64, U8};
+/// let mut acc = SignedDecimalAccumulator::<U8>::new();
+/// acc.push(SignedDecimalU64::MAX);
+/// acc.push(SignedDecimalU64::MAX); // the running total exceeds u64 here
+/// acc.push(SignedDecimalU64::MIN);
+/// assert_eq!(acc.finish(), Ok(SignedDecimalU64::MAX));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SignedDecimalAccumulator<S: ScaleMetrics> {
+    total: i128,
+    _scale: PhantomData<S>,
+}
+
+impl<S: ScaleMetrics> SignedDecimalAccumulator<S> {
+    /// An empty accumulator (total zero).
+    pub const fn new() -> Self {
+        Self {
+            total: 0,
+            _scale: PhantomData,
+        }
+    }
+
+    /// Add one value to the total.
+    #[inline]
+    pub fn push(&mut self, value: SignedDecimalU64<S>) {
+        self.total = self.total.wrapping_add(value.into_unscaled_i128());
+    }
+
+    /// Add the total of another accumulator (e.g. a per-thread partial sum).
+    #[inline]
+    pub fn merge(&mut self, other: Self) {
+        self.total = self.total.wrapping_add(other.total);
+    }
+
+    /// The exact total as a signed unscaled integer in scale `S`, matching
+    /// `SignedDecimalU64::into_unscaled_i128`.
+    #[inline]
+    pub const fn unscaled_total(&self) -> i128 {
+        self.total
+    }
+
+    /// The total as a `SignedDecimalU64<S>`, or `Overflow`/`NegativeOverflow` if it
+    /// does not fit.
+    pub fn finish(self) -> Result<SignedDecimalU64<S>> {
+        SignedDecimalU64::try_from(self.total)
+    }
+
+    /// Like `finish`, but clamps an out-of-range total to `MIN`/`MAX`.
+    pub fn finish_saturating(self) -> SignedDecimalU64<S> {
+        match self.finish() {
+            Ok(v) => v,
+            Err(MathError::NegativeOverflow) => SignedDecimalU64::MIN,
+            Err(_) => SignedDecimalU64::MAX,
+        }
+    }
+}
+
+impl<S: ScaleMetrics> Default for SignedDecimalAccumulator<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: ScaleMetrics> Extend<SignedDecimalU64<S>> for SignedDecimalAccumulator<S> {
+    fn extend<I: IntoIterator<Item = SignedDecimalU64<S>>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a, S: ScaleMetrics> Extend<&'a SignedDecimalU64<S>> for SignedDecimalAccumulator<S> {
+    fn extend<I: IntoIterator<Item = &'a SignedDecimalU64<S>>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(deref_copy(value));
+        }
+    }
+}
+
+impl<S: ScaleMetrics> FromIterator<SignedDecimalU64<S>> for SignedDecimalAccumulator<S> {
+    fn from_iter<I: IntoIterator<Item = SignedDecimalU64<S>>>(iter: I) -> Self {
+        let mut acc = Self::new();
+        acc.extend(iter);
+        acc
+    }
+}
+
+impl<S: ScaleMetrics> Sum<SignedDecimalU64<S>> for SignedDecimalAccumulator<S> {
+    fn sum<I: Iterator<Item = SignedDecimalU64<S>>>(iter: I) -> Self {
+        iter.collect()
+    }
+}
+
+impl<'a, S: ScaleMetrics> Sum<&'a SignedDecimalU64<S>> for SignedDecimalAccumulator<S> {
+    fn sum<I: Iterator<Item = &'a SignedDecimalU64<S>>>(iter: I) -> Self {
+        let mut acc = Self::new();
+        acc.extend(iter);
+        acc
+    }
+}
+
+impl<S: ScaleMetrics> Sum for SignedDecimalAccumulator<S> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(), |mut acc, part| {
+            acc.merge(part);
+            acc
+        })
+    }
+}
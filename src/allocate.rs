@@ -0,0 +1,102 @@
+//! Pro-rata allocation of a `SignedDecimalU64<S>` that preserves the total.
+//!
+//! Enabled with crate feature `alloc`. Each part first gets its floored
+//! pro-rata share in whole units of the last place; the units left over are
+//! then handed out one at a time by the largest-remainder method, so the parts
+//! always sum exactly to the original value.
+
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use decimal64::ScaleMetrics;
+
+use crate::{from_unscaled, SignedDecimalU64};
+
+impl<S: ScaleMetrics> SignedDecimalU64<S> {
+    /// Split `self` into parts proportional to `weights`, returning `None` if
+    /// `weights` is empty or sums to zero.
+    ///
+    /// Every part has the sign of `self`. Leftover units go to the parts with
+    /// the largest discarded remainder; ties go to the earlier part.
+    pub fn checked_allocate(&self, weights: &[u64]) -> Option<Vec<Self>> {
+        let total = self.unscaled() as u128;
+        let weight_sum: u128 = weights.iter().map(|&w| w as u128).sum();
+        if weight_sum == 0 {
+            return None;
+        }
+
+        let mut parts = Vec::with_capacity(weights.len());
+        let mut remainders = Vec::with_capacity(weights.len());
+        let mut allocated: u128 = 0;
+        for (i, &w) in weights.iter().enumerate() {
+            let exact = total * w as u128;
+            let share = exact / weight_sum;
+            allocated += share;
+            parts.push(share as u64);
+            remainders.push((exact % weight_sum, i));
+        }
+
+        // Fewer than `weights.len()` units are left; give them to the largest
+        // remainders (stable sort keeps earlier indices first on ties).
+        let leftover = (total - allocated) as usize;
+        remainders.sort_by_key(|&(r, _)| Reverse(r));
+        for &(_, i) in &remainders[..leftover] {
+            parts[i] += 1;
+        }
+
+        let negative = self.is_negative();
+        Some(
+            parts
+                .into_iter()
+                .map(|p| Self::new(negative, from_unscaled::<S>(p)))
+                .collect(),
+        )
+    }
+
+    /// Split `self` into parts proportional to `weights`; see `checked_allocate`.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{sdec, U2};
+    /// let parts = sdec!(U2, "100.00").allocate(&[1, 1, 1]);
+    /// let shown: Vec<_> = parts.iter().map(|p| p.to_string()).collect();
+    /// assert_eq!(shown, ["33.34", "33.33", "33.33"]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` is empty or sums to zero.
+    pub fn allocate(&self, weights: &[u64]) -> Vec<Self> {
+        self.checked_allocate(weights)
+            .expect("SignedDecimalU64::allocate with zero total weight")
+    }
+
+    /// Split `self` into `n` parts that differ by at most one unit in the last
+    /// place, returning `None` if `n` is zero. Larger parts come first.
+    pub fn checked_split_evenly(&self, n: usize) -> Option<Vec<Self>> {
+        if n == 0 {
+            return None;
+        }
+        let total = self.unscaled() as u128;
+        let base = (total / n as u128) as u64;
+        let extra = (total % n as u128) as usize;
+        let negative = self.is_negative();
+        Some(
+            (0..n)
+                .map(|i| {
+                    let part = base + (i < extra) as u64;
+                    Self::new(negative, from_unscaled::<S>(part))
+                })
+                .collect(),
+        )
+    }
+
+    /// Split `self` into `n` parts that differ by at most one unit in the last
+    /// place; see `checked_split_evenly`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn split_evenly(&self, n: usize) -> Vec<Self> {
+        self.checked_split_evenly(n)
+            .expect("SignedDecimalU64::split_evenly into zero parts")
+    }
+}
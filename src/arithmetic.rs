@@ -1,15 +1,23 @@
 //! Arithmetic for `SignedDecimalU64<S>`.
 //
-// - `Add/Sub/Mul/Div` operators: panic on overflow/underflow/div-by-zero
+// - `Add/Sub/Mul/Div/Rem` operators: panic on overflow/underflow/div-by-zero
 //   (matching `DecimalU64<S>` operator semantics).
 // - `checked_add/sub/mul/div`: return `Option<Self>` on failure.
+// - `try_add/sub/mul/div`: return `error::Result<Self>` naming the failure.
+// - `saturating_add/sub/mul/div`: clamp to `MIN`/`MAX` instead of failing.
+// - `overflowing_*`/`wrapping_*`: the magnitude wraps modulo 2^64 while the
+//   sign of the exact result is kept (zero stays normalized).
+// - `checked_*_into`: mixed-scale arithmetic with an explicit output scale and
+//   rounding mode; operators between different scales yield the finer scale.
 
 use core::iter::Sum;
 use core::mem;
-use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
-use decimal64::{DecimalU64, ScaleMetrics};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use decimal64::{DecimalU64, ScaleMetrics, U0, U1, U2, U3, U4, U5, U6, U7, U8};
 
-use crate::SignedDecimalU64;
+use crate::error::{MathError, Result};
+use crate::round::{div_round, pow10_u128, rescale_round, RoundingMode};
+use crate::{from_unscaled, SignedDecimalU64};
 
 impl<S: ScaleMetrics> SignedDecimalU64<S> {
     /// Checked addition. Returns `None` on overflow.
@@ -38,31 +46,579 @@ impl<S: ScaleMetrics> SignedDecimalU64<S> {
         self.checked_add(-rhs)
     }
 
-    /// Checked multiplication. Returns `None` on overflow.
+    /// Checked multiplication, truncating toward zero. Returns `None` on overflow.
+    ///
+    /// The product is formed exactly in a `u128` intermediate, so only results
+    /// whose magnitude does not fit in `u64` fail.
+    #[inline]
     pub fn checked_mul(self, rhs: Self) -> Option<Self> {
-        let a_mag = self.mag;
-        let b_mag = rhs.mag;
+        self.checked_mul_round(rhs, RoundingMode::TowardZero)
+    }
 
-        if a_mag.unscaled == 0 || b_mag.unscaled == 0 {
-            return Some(Self::ZERO);
+    /// Checked division, truncating toward zero. Returns `None` on div-by-zero or overflow.
+    ///
+    /// The scaled dividend is formed exactly in a `u128` intermediate, so only
+    /// quotients whose magnitude does not fit in `u64` fail.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_div_round(rhs, RoundingMode::TowardZero)
+    }
+
+    /// Checked multiplication with explicit rounding of the discarded digits.
+    ///
+    /// The exact product is formed in a `u128` intermediate and rounded once
+    /// with `mode`, honoring the sign for the directed modes. Returns `None` on
+    /// overflow.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{round::RoundingMode, sdec, U2};
+    /// let notional = sdec!(U2, "-1000.10");
+    /// let rate = sdec!(U2, "0.05");
+    /// // exact: -50.005
+    /// let fee = notional.checked_mul_round(rate, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(fee.to_string(), "-50.00");
+    /// let fee = notional.checked_mul_round(rate, RoundingMode::AwayFromZero).unwrap();
+    /// assert_eq!(fee.to_string(), "-50.01");
+    /// ```
+    #[inline]
+    pub fn checked_mul_round(self, rhs: Self, mode: RoundingMode) -> Option<Self> {
+        self.checked_mul_into::<S, S>(rhs, mode)
+    }
+
+    /// Checked division with explicit rounding of the quotient.
+    ///
+    /// The exact quotient is rounded once with `mode`, honoring the sign for
+    /// the directed modes. Returns `None` on division by zero or overflow.
+    #[inline]
+    pub fn checked_div_round(self, rhs: Self, mode: RoundingMode) -> Option<Self> {
+        self.checked_div_into::<S, S>(rhs, mode)
+    }
+}
+
+// --- Result-returning arithmetic ---
+
+impl<S: ScaleMetrics> SignedDecimalU64<S> {
+    /// Addition returning `Overflow`/`NegativeOverflow` when the sum is out of range.
+    #[inline]
+    pub fn try_add(self, rhs: Self) -> Result<Self> {
+        // Only same-sign operands can leave the range, in the direction of `self`.
+        let neg = self.is_negative();
+        self.checked_add(rhs).ok_or(MathError::out_of_range(neg))
+    }
+
+    /// Subtraction returning `Overflow`/`NegativeOverflow` when the difference is out of range.
+    #[inline]
+    pub fn try_sub(self, rhs: Self) -> Result<Self> {
+        self.try_add(-rhs)
+    }
+
+    /// Multiplication (truncating toward zero) returning `Overflow`/`NegativeOverflow`
+    /// when the product is out of range.
+    #[inline]
+    pub fn try_mul(self, rhs: Self) -> Result<Self> {
+        let neg = self.is_negative() ^ rhs.is_negative();
+        self.checked_mul(rhs).ok_or(MathError::out_of_range(neg))
+    }
+
+    /// Division (truncating toward zero) returning `DivisionByZero` for a zero
+    /// divisor and `Overflow`/`NegativeOverflow` when the quotient is out of range.
+    #[inline]
+    pub fn try_div(self, rhs: Self) -> Result<Self> {
+        if rhs.is_zero() {
+            return Err(MathError::DivisionByZero);
         }
+        let neg = self.is_negative() ^ rhs.is_negative();
+        self.checked_div(rhs).ok_or(MathError::out_of_range(neg))
+    }
+}
 
-        let neg = (self.negative && a_mag.unscaled != 0) ^ (rhs.negative && b_mag.unscaled != 0);
-        a_mag.checked_mul(b_mag).map(|m| Self::new(neg, m))
+// --- Saturating arithmetic ---
+
+impl<S: ScaleMetrics> SignedDecimalU64<S> {
+    /// `MIN` for a negative out-of-range result, `MAX` otherwise.
+    #[inline]
+    pub(crate) const fn saturated(negative: bool) -> Self {
+        if negative {
+            Self::MIN
+        } else {
+            Self::MAX
+        }
     }
 
-    /// Checked division. Returns `None` on div-by-zero or overflow.
-    pub fn checked_div(self, rhs: Self) -> Option<Self> {
-        let a_mag = self.mag;
-        let b_mag = rhs.mag;
-        if b_mag.unscaled == 0 {
+    /// Saturating addition: clamps to `MIN`/`MAX` on overflow.
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        let neg = self.is_negative();
+        self.checked_add(rhs).unwrap_or(Self::saturated(neg))
+    }
+
+    /// Saturating subtraction: clamps to `MIN`/`MAX` on overflow.
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self.saturating_add(-rhs)
+    }
+
+    /// Saturating multiplication (truncating toward zero): clamps to
+    /// `MIN`/`MAX` on overflow.
+    #[inline]
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        let neg = self.is_negative() ^ rhs.is_negative();
+        self.checked_mul(rhs).unwrap_or(Self::saturated(neg))
+    }
+
+    /// Saturating division (truncating toward zero): clamps to `MIN`/`MAX`
+    /// on overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero, like the primitive integer `saturating_div`.
+    #[inline]
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        assert!(!rhs.is_zero(), "SignedDecimalU64::saturating_div by zero");
+        let neg = self.is_negative() ^ rhs.is_negative();
+        self.checked_div(rhs).unwrap_or(Self::saturated(neg))
+    }
+}
+
+// --- Wrapping / overflowing arithmetic ---
+//
+// For a sign-magnitude type the wrap is defined on the magnitude: the exact
+// result (truncated toward zero for `mul`) keeps its sign and its magnitude is
+// reduced modulo 2^64. A magnitude that wraps to exactly zero yields `ZERO`.
+
+impl<S: ScaleMetrics> SignedDecimalU64<S> {
+    /// Addition returning the wrapped result and whether the magnitude overflowed.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let a_neg = self.is_negative();
+        if a_neg == rhs.is_negative() {
+            let (m, overflow) = self.unscaled().overflowing_add(rhs.unscaled());
+            (Self::new(a_neg, from_unscaled::<S>(m)), overflow)
+        } else {
+            // Opposite signs shrink the magnitude and can never overflow.
+            let sum = self
+                .checked_add(rhs)
+                .expect("opposite signs cannot overflow");
+            (sum, false)
+        }
+    }
+
+    /// Subtraction returning the wrapped result and whether the magnitude overflowed.
+    #[inline]
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        self.overflowing_add(-rhs)
+    }
+
+    /// Multiplication (truncating toward zero) returning the wrapped result and
+    /// whether the magnitude overflowed.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let neg = self.is_negative() ^ rhs.is_negative();
+        let product = self.unscaled() as u128 * rhs.unscaled() as u128;
+        let exact = product / S::SCALE_FACTOR as u128;
+        let overflow = exact > u64::MAX as u128;
+        (Self::new(neg, from_unscaled::<S>(exact as u64)), overflow)
+    }
+
+    /// Wrapping addition: the magnitude wraps modulo 2^64.
+    #[inline]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Wrapping subtraction: the magnitude wraps modulo 2^64.
+    #[inline]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Wrapping multiplication (truncating toward zero): the magnitude wraps
+    /// modulo 2^64.
+    #[inline]
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+}
+
+// --- Remainder and Euclidean division ---
+//
+// Sign rules follow Rust's signed integers: `%` takes the sign of the dividend,
+// `rem_euclid` is never negative, and `div_euclid` is the matching integer
+// quotient so that `self == rhs * div_euclid + rem_euclid`.
+
+impl<S: ScaleMetrics> SignedDecimalU64<S> {
+    /// Checked remainder (sign of `self`). Returns `None` if `rhs` is zero.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{sdec, U2};
+    /// assert_eq!(sdec!(U2, "-7.50").checked_rem(sdec!(U2, "2")).unwrap().to_string(), "-1.50");
+    /// ```
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
             return None;
         }
-        if a_mag.unscaled == 0 {
-            return Some(Self::ZERO);
+        let r = self.unscaled() % rhs.unscaled();
+        Some(Self::new(self.is_negative(), from_unscaled::<S>(r)))
+    }
+
+    /// Checked Euclidean remainder: always in `0 <= r < |rhs|`.
+    /// Returns `None` if `rhs` is zero.
+    pub fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
         }
-        let neg = (self.negative && a_mag.unscaled != 0) ^ (rhs.negative && b_mag.unscaled != 0);
-        a_mag.checked_div(b_mag).map(|m| Self::new(neg, m))
+        let b = rhs.unscaled();
+        let r = self.unscaled() % b;
+        let r = if self.is_negative() && r != 0 {
+            b - r
+        } else {
+            r
+        };
+        Some(Self::from_mag(from_unscaled::<S>(r)))
+    }
+
+    /// Euclidean remainder: always in `0 <= r < |rhs|`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        self.checked_rem_euclid(rhs)
+            .expect("SignedDecimalU64::rem_euclid by zero")
+    }
+
+    /// Sign and magnitude of the Euclidean integer quotient, or `None` on
+    /// division by zero. `q + 1` cannot overflow: a remainder implies `rhs > 1`.
+    fn euclid_quotient(&self, rhs: &Self) -> Option<(bool, u64)> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let (a, b) = (self.unscaled(), rhs.unscaled());
+        let q = a / b;
+        // A negative dividend with a remainder moves one step further from zero.
+        let q = if self.is_negative() && a % b != 0 {
+            q + 1
+        } else {
+            q
+        };
+        Some((self.is_negative() ^ rhs.is_negative(), q))
+    }
+
+    /// Checked Euclidean quotient as a whole number in scale `S`.
+    /// Returns `None` on division by zero or if the quotient is out of range.
+    pub fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+        let (neg, q) = self.euclid_quotient(&rhs)?;
+        let mag = q.checked_mul(S::SCALE_FACTOR)?;
+        Some(Self::new(neg, from_unscaled::<S>(mag)))
+    }
+
+    /// Euclidean quotient as a whole number in scale `S`.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{sdec, U2};
+    /// let (a, b) = (sdec!(U2, "-7.50"), sdec!(U2, "2"));
+    /// assert_eq!(a.div_euclid(b).to_string(), "-4.00");
+    /// assert_eq!(a.rem_euclid(b).to_string(), "0.50");
+    /// assert_eq!(b * a.div_euclid(b) + a.rem_euclid(b), a);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the quotient is out of range.
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        self.checked_div_euclid(rhs)
+            .expect("SignedDecimalU64::div_euclid by zero or overflow")
+    }
+
+    /// Checked floored integer quotient (`⌊self / rhs⌋`) as an `i64`, e.g. a lot count.
+    /// Returns `None` on division by zero or if the quotient does not fit in `i64`.
+    pub fn checked_div_floor(self, rhs: Self) -> Option<i64> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let (a, b) = (self.unscaled(), rhs.unscaled());
+        let neg = self.is_negative() ^ rhs.is_negative();
+        let q = a / b;
+        // Negative quotients with a remainder round toward -inf.
+        let q = if neg && a % b != 0 {
+            q as i128 + 1
+        } else {
+            q as i128
+        };
+        i64::try_from(if neg { -q } else { q }).ok()
+    }
+
+    /// Floored integer quotient (`⌊self / rhs⌋`) as an `i64`, e.g. the number of
+    /// whole lots of size `rhs` in `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the quotient does not fit in `i64`.
+    #[inline]
+    pub fn div_floor(self, rhs: Self) -> i64 {
+        self.checked_div_floor(rhs)
+            .expect("SignedDecimalU64::div_floor by zero or overflow")
+    }
+}
+
+// --- Scalar arithmetic with primitive integers ---
+//
+// The integer is an exact, unscaled factor: `price * 3_i64` multiplies the
+// unscaled magnitude by 3 instead of first building `3` in scale `S`.
+
+/// Sign and magnitude of a primitive integer used as a scalar factor.
+trait IntScalar: Copy {
+    fn sign_mag(self) -> (bool, u64);
+}
+
+impl IntScalar for i64 {
+    #[inline]
+    fn sign_mag(self) -> (bool, u64) {
+        (self < 0, self.unsigned_abs())
+    }
+}
+
+impl IntScalar for u64 {
+    #[inline]
+    fn sign_mag(self) -> (bool, u64) {
+        (false, self)
+    }
+}
+
+impl IntScalar for i32 {
+    #[inline]
+    fn sign_mag(self) -> (bool, u64) {
+        (self < 0, self.unsigned_abs() as u64)
+    }
+}
+
+impl<S: ScaleMetrics> SignedDecimalU64<S> {
+    fn checked_mul_scalar<I: IntScalar>(self, rhs: I) -> Option<Self> {
+        let (neg, m) = rhs.sign_mag();
+        let mag = self.unscaled().checked_mul(m)?;
+        Some(Self::new(self.is_negative() ^ neg, from_unscaled::<S>(mag)))
+    }
+
+    fn checked_div_scalar<I: IntScalar>(self, rhs: I) -> Option<Self> {
+        let (neg, m) = rhs.sign_mag();
+        let mag = self.unscaled().checked_div(m)?;
+        Some(Self::new(self.is_negative() ^ neg, from_unscaled::<S>(mag)))
+    }
+
+    /// Checked multiplication by an exact integer factor. Returns `None` on overflow.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{sdec, U4};
+    /// let price = sdec!(U4, "101.2345");
+    /// assert_eq!(price.checked_mul_int(-300).unwrap().to_string(), "-30370.3500");
+    /// ```
+    #[inline]
+    pub fn checked_mul_int(self, rhs: i64) -> Option<Self> {
+        self.checked_mul_scalar(rhs)
+    }
+
+    /// Checked division by an exact integer, truncating toward zero.
+    /// Returns `None` if `rhs` is zero.
+    #[inline]
+    pub fn checked_div_int(self, rhs: i64) -> Option<Self> {
+        self.checked_div_scalar(rhs)
+    }
+}
+
+macro_rules! impl_int_scalar_ops {
+    ($($int:ty),* $(,)?) => {$(
+        impl<S: ScaleMetrics> Mul<$int> for SignedDecimalU64<S> {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: $int) -> Self::Output {
+                self.checked_mul_scalar(rhs)
+                    .expect("SignedDecimalU64::mul overflow")
+            }
+        }
+
+        impl<S: ScaleMetrics> Mul<&$int> for SignedDecimalU64<S> {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: &$int) -> Self::Output {
+                self * *rhs
+            }
+        }
+
+        impl<S: ScaleMetrics> Mul<$int> for &SignedDecimalU64<S> {
+            type Output = SignedDecimalU64<S>;
+            #[inline]
+            fn mul(self, rhs: $int) -> Self::Output {
+                deref_copy(self) * rhs
+            }
+        }
+
+        impl<S: ScaleMetrics> Mul<&$int> for &SignedDecimalU64<S> {
+            type Output = SignedDecimalU64<S>;
+            #[inline]
+            fn mul(self, rhs: &$int) -> Self::Output {
+                self * *rhs
+            }
+        }
+
+        impl<S: ScaleMetrics> Mul<SignedDecimalU64<S>> for $int {
+            type Output = SignedDecimalU64<S>;
+            #[inline]
+            fn mul(self, rhs: SignedDecimalU64<S>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl<S: ScaleMetrics> Mul<&SignedDecimalU64<S>> for $int {
+            type Output = SignedDecimalU64<S>;
+            #[inline]
+            fn mul(self, rhs: &SignedDecimalU64<S>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl<S: ScaleMetrics> Div<$int> for SignedDecimalU64<S> {
+            type Output = Self;
+            #[inline]
+            fn div(self, rhs: $int) -> Self::Output {
+                self.checked_div_scalar(rhs)
+                    .expect("SignedDecimalU64::div by zero")
+            }
+        }
+
+        impl<S: ScaleMetrics> Div<&$int> for SignedDecimalU64<S> {
+            type Output = Self;
+            #[inline]
+            fn div(self, rhs: &$int) -> Self::Output {
+                self / *rhs
+            }
+        }
+
+        impl<S: ScaleMetrics> Div<$int> for &SignedDecimalU64<S> {
+            type Output = SignedDecimalU64<S>;
+            #[inline]
+            fn div(self, rhs: $int) -> Self::Output {
+                deref_copy(self) / rhs
+            }
+        }
+
+        impl<S: ScaleMetrics> Div<&$int> for &SignedDecimalU64<S> {
+            type Output = SignedDecimalU64<S>;
+            #[inline]
+            fn div(self, rhs: &$int) -> Self::Output {
+                self / *rhs
+            }
+        }
+
+        impl<S: ScaleMetrics> MulAssign<$int> for SignedDecimalU64<S> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $int) {
+                *self = mem::take(self) * rhs;
+            }
+        }
+
+        impl<S: ScaleMetrics> DivAssign<$int> for SignedDecimalU64<S> {
+            #[inline]
+            fn div_assign(&mut self, rhs: $int) {
+                *self = mem::take(self) / rhs;
+            }
+        }
+    )*};
+}
+
+impl_int_scalar_ops!(i64, u64, i32);
+
+// --- Mixed-scale arithmetic ---
+//
+// The exact result is formed in a `u128` intermediate and rounded once into the
+// output scale `T` (the same path `checked_to_scale` takes), so combining a U2
+// amount with a U8 quantity never double-rounds.
+
+impl<S: ScaleMetrics> SignedDecimalU64<S> {
+    /// Checked addition across scales: `self` (scale `S`) plus `rhs` (scale `R`),
+    /// rounded into scale `T` with `mode`. Returns `None` on overflow.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{round::RoundingMode, sdec, U2, U4, U8};
+    /// let cash = sdec!(U2, "10.25");
+    /// let fee = sdec!(U8, "-0.00125");
+    /// let net = cash.checked_add_into::<U8, U4>(fee, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(net.to_string(), "10.2488");
+    /// ```
+    pub fn checked_add_into<R: ScaleMetrics, T: ScaleMetrics>(
+        self,
+        rhs: SignedDecimalU64<R>,
+        mode: RoundingMode,
+    ) -> Option<SignedDecimalU64<T>> {
+        // Bring both operands to the finer input scale; both fit in i128 exactly.
+        let wide = S::SCALE.max(R::SCALE) as i32;
+        let a = self.into_unscaled_i128() * pow10_u128((wide - S::SCALE as i32) as u32)? as i128;
+        let b = rhs.into_unscaled_i128() * pow10_u128((wide - R::SCALE as i32) as u32)? as i128;
+        let sum = a + b;
+        let neg = sum < 0;
+        let mag = rescale_round(sum.unsigned_abs(), T::SCALE as i32 - wide, neg, mode)?;
+        Some(SignedDecimalU64::<T>::new(neg, from_unscaled::<T>(mag)))
+    }
+
+    /// Checked subtraction across scales, rounded into scale `T` with `mode`.
+    /// Returns `None` on overflow.
+    pub fn checked_sub_into<R: ScaleMetrics, T: ScaleMetrics>(
+        self,
+        rhs: SignedDecimalU64<R>,
+        mode: RoundingMode,
+    ) -> Option<SignedDecimalU64<T>> {
+        self.checked_add_into::<R, T>(-rhs, mode)
+    }
+
+    /// Checked multiplication across scales, rounded into scale `T` with `mode`.
+    /// Returns `None` on overflow.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{round::RoundingMode, sdec, U2, U4, U8};
+    /// let price = sdec!(U4, "101.2345");
+    /// let qty = sdec!(U8, "-0.5");
+    /// let notional = price.checked_mul_into::<U8, U2>(qty, RoundingMode::HalfUp).unwrap();
+    /// assert_eq!(notional.to_string(), "-50.62");
+    /// ```
+    pub fn checked_mul_into<R: ScaleMetrics, T: ScaleMetrics>(
+        self,
+        rhs: SignedDecimalU64<R>,
+        mode: RoundingMode,
+    ) -> Option<SignedDecimalU64<T>> {
+        // The product of the unscaled values is exact at scale S + R.
+        let neg = self.is_negative() ^ rhs.is_negative();
+        let product = self.unscaled() as u128 * rhs.unscaled() as u128;
+        let shift = T::SCALE as i32 - S::SCALE as i32 - R::SCALE as i32;
+        let mag = rescale_round(product, shift, neg, mode)?;
+        Some(SignedDecimalU64::<T>::new(neg, from_unscaled::<T>(mag)))
+    }
+
+    /// Checked division across scales, rounded into scale `T` with `mode`.
+    /// Returns `None` on division by zero or overflow.
+    pub fn checked_div_into<R: ScaleMetrics, T: ScaleMetrics>(
+        self,
+        rhs: SignedDecimalU64<R>,
+        mode: RoundingMode,
+    ) -> Option<SignedDecimalU64<T>> {
+        if rhs.is_zero() {
+            return None;
+        }
+        // unscaled(T) = a * 10^(T + R - S) / b; move the power of ten to
+        // whichever side keeps it non-negative.
+        let neg = self.is_negative() ^ rhs.is_negative();
+        let shift = T::SCALE as i32 + R::SCALE as i32 - S::SCALE as i32;
+        let (n, d) = if shift >= 0 {
+            (
+                self.unscaled() as u128 * pow10_u128(shift as u32)?,
+                rhs.unscaled() as u128,
+            )
+        } else {
+            (
+                self.unscaled() as u128,
+                rhs.unscaled() as u128 * pow10_u128(shift.unsigned_abs())?,
+            )
+        };
+        let mag = div_round(n, d, neg, mode)?;
+        Some(SignedDecimalU64::<T>::new(neg, from_unscaled::<T>(mag)))
     }
 }
 
@@ -104,6 +660,15 @@ impl<S: ScaleMetrics> Div for SignedDecimalU64<S> {
     }
 }
 
+impl<S: ScaleMetrics> Rem for SignedDecimalU64<S> {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.checked_rem(rhs)
+            .expect("SignedDecimalU64::rem by zero")
+    }
+}
+
 impl<S: ScaleMetrics> AddAssign for SignedDecimalU64<S> {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
@@ -132,6 +697,67 @@ impl<S: ScaleMetrics> DivAssign for SignedDecimalU64<S> {
     }
 }
 
+impl<S: ScaleMetrics> RemAssign for SignedDecimalU64<S> {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = core::mem::take(self) % rhs;
+    }
+}
+
+// --- Reference operators ---
+//
+// `&a op &b`, `&a op b` and `a op &b` for every binary operator, so generic code
+// bounded on `for<'a> &'a T: Add<&'a T>` and friends works.
+
+/// Copy a value out of a reference without requiring `S: Copy`.
+#[inline]
+pub(crate) fn deref_copy<S: ScaleMetrics>(x: &SignedDecimalU64<S>) -> SignedDecimalU64<S> {
+    SignedDecimalU64::new(x.negative, x.to_unsigned())
+}
+
+macro_rules! impl_ref_binops {
+    ($($trait:ident::$method:ident, $assign:ident::$assign_method:ident;)*) => {$(
+        impl<S: ScaleMetrics> $trait<&SignedDecimalU64<S>> for SignedDecimalU64<S> {
+            type Output = SignedDecimalU64<S>;
+            #[inline]
+            fn $method(self, rhs: &SignedDecimalU64<S>) -> Self::Output {
+                $trait::$method(self, deref_copy(rhs))
+            }
+        }
+
+        impl<S: ScaleMetrics> $trait<SignedDecimalU64<S>> for &SignedDecimalU64<S> {
+            type Output = SignedDecimalU64<S>;
+            #[inline]
+            fn $method(self, rhs: SignedDecimalU64<S>) -> Self::Output {
+                $trait::$method(deref_copy(self), rhs)
+            }
+        }
+
+        impl<S: ScaleMetrics> $trait<&SignedDecimalU64<S>> for &SignedDecimalU64<S> {
+            type Output = SignedDecimalU64<S>;
+            #[inline]
+            fn $method(self, rhs: &SignedDecimalU64<S>) -> Self::Output {
+                $trait::$method(deref_copy(self), deref_copy(rhs))
+            }
+        }
+
+        impl<S: ScaleMetrics> $assign<&SignedDecimalU64<S>> for SignedDecimalU64<S> {
+            #[inline]
+            fn $assign_method(&mut self, rhs: &SignedDecimalU64<S>) {
+                $assign::$assign_method(self, deref_copy(rhs));
+            }
+        }
+    )*};
+}
+
+impl_ref_binops! {
+    Add::add, AddAssign::add_assign;
+    Sub::sub, SubAssign::sub_assign;
+    Mul::mul, MulAssign::mul_assign;
+    Div::div, DivAssign::div_assign;
+    Rem::rem, RemAssign::rem_assign;
+}
+
 // --- Iteration helpers ---
 
 impl<S: ScaleMetrics> Sum for SignedDecimalU64<S> {
@@ -218,6 +844,72 @@ impl<S: ScaleMetrics> SubAssign<&DecimalU64<S>> for SignedDecimalU64<S> {
     }
 }
 
+impl<S: ScaleMetrics> Mul<DecimalU64<S>> for SignedDecimalU64<S> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: DecimalU64<S>) -> Self::Output {
+        self * SignedDecimalU64::from(rhs)
+    }
+}
+
+impl<S: ScaleMetrics> MulAssign<DecimalU64<S>> for SignedDecimalU64<S> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: DecimalU64<S>) {
+        *self = mem::take(self) * SignedDecimalU64::from(rhs);
+    }
+}
+
+impl<S: ScaleMetrics> Div<DecimalU64<S>> for SignedDecimalU64<S> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: DecimalU64<S>) -> Self::Output {
+        self / SignedDecimalU64::from(rhs)
+    }
+}
+
+impl<S: ScaleMetrics> DivAssign<DecimalU64<S>> for SignedDecimalU64<S> {
+    #[inline]
+    fn div_assign(&mut self, rhs: DecimalU64<S>) {
+        *self = mem::take(self) / SignedDecimalU64::from(rhs);
+    }
+}
+
+// Mul/Div with &DecimalU64<S>
+impl<S: ScaleMetrics> Mul<&DecimalU64<S>> for SignedDecimalU64<S> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: &DecimalU64<S>) -> Self::Output {
+        let mag = DecimalU64::<S>::from_raw(rhs.unscaled);
+        self * SignedDecimalU64::from_mag(mag)
+    }
+}
+
+impl<S: ScaleMetrics> Div<&DecimalU64<S>> for SignedDecimalU64<S> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: &DecimalU64<S>) -> Self::Output {
+        let mag = DecimalU64::<S>::from_raw(rhs.unscaled);
+        self / SignedDecimalU64::from_mag(mag)
+    }
+}
+
+// MulAssign/DivAssign with &DecimalU64<S> (no move from *self or *rhs)
+impl<S: ScaleMetrics> MulAssign<&DecimalU64<S>> for SignedDecimalU64<S> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &DecimalU64<S>) {
+        let mag = DecimalU64::<S>::from_raw(rhs.unscaled);
+        *self = mem::take(self) * SignedDecimalU64::from_mag(mag);
+    }
+}
+
+impl<S: ScaleMetrics> DivAssign<&DecimalU64<S>> for SignedDecimalU64<S> {
+    #[inline]
+    fn div_assign(&mut self, rhs: &DecimalU64<S>) {
+        let mag = DecimalU64::<S>::from_raw(rhs.unscaled);
+        *self = mem::take(self) / SignedDecimalU64::from_mag(mag);
+    }
+}
+
 impl<S: ScaleMetrics> core::iter::Product for SignedDecimalU64<S> {
     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
         iter.fold(SignedDecimalU64::ONE, |acc, x| acc * x)
@@ -235,3 +927,64 @@ impl<'a, S: ScaleMetrics> core::iter::Product<&'a SignedDecimalU64<S>> for Signe
         })
     }
 }
+
+// --- Mixed-scale operators ---
+//
+// Operands with different scales produce a result in the finer of the two
+// scales. `Add`/`Sub` are exact there; `Mul`/`Div` truncate toward zero like
+// the same-scale operators. All of them panic on overflow or division by zero.
+
+macro_rules! impl_mixed_scale_ops {
+    ($($coarse:ident => $fine:ident),* $(,)?) => {$(
+        impl_mixed_scale_ops!(@one $coarse, $fine, $fine);
+        impl_mixed_scale_ops!(@one $fine, $coarse, $fine);
+    )*};
+    (@one $lhs:ident, $rhs:ident, $out:ident) => {
+        impl Add<SignedDecimalU64<$rhs>> for SignedDecimalU64<$lhs> {
+            type Output = SignedDecimalU64<$out>;
+            #[inline]
+            fn add(self, rhs: SignedDecimalU64<$rhs>) -> Self::Output {
+                self.checked_add_into::<$rhs, $out>(rhs, RoundingMode::TowardZero)
+                    .expect("SignedDecimalU64::add overflow")
+            }
+        }
+
+        impl Sub<SignedDecimalU64<$rhs>> for SignedDecimalU64<$lhs> {
+            type Output = SignedDecimalU64<$out>;
+            #[inline]
+            fn sub(self, rhs: SignedDecimalU64<$rhs>) -> Self::Output {
+                self.checked_sub_into::<$rhs, $out>(rhs, RoundingMode::TowardZero)
+                    .expect("SignedDecimalU64::sub overflow")
+            }
+        }
+
+        impl Mul<SignedDecimalU64<$rhs>> for SignedDecimalU64<$lhs> {
+            type Output = SignedDecimalU64<$out>;
+            #[inline]
+            fn mul(self, rhs: SignedDecimalU64<$rhs>) -> Self::Output {
+                self.checked_mul_into::<$rhs, $out>(rhs, RoundingMode::TowardZero)
+                    .expect("SignedDecimalU64::mul overflow")
+            }
+        }
+
+        impl Div<SignedDecimalU64<$rhs>> for SignedDecimalU64<$lhs> {
+            type Output = SignedDecimalU64<$out>;
+            #[inline]
+            fn div(self, rhs: SignedDecimalU64<$rhs>) -> Self::Output {
+                self.checked_div_into::<$rhs, $out>(rhs, RoundingMode::TowardZero)
+                    .expect("SignedDecimalU64::div by zero or overflow")
+            }
+        }
+    };
+}
+
+impl_mixed_scale_ops!(
+    U0 => U1, U0 => U2, U0 => U3, U0 => U4, U0 => U5, U0 => U6, U0 => U7, U0 => U8,
+    U1 => U2, U1 => U3, U1 => U4, U1 => U5, U1 => U6, U1 => U7, U1 => U8,
+    U2 => U3, U2 => U4, U2 => U5, U2 => U6, U2 => U7, U2 => U8,
+    U3 => U4, U3 => U5, U3 => U6, U3 => U7, U3 => U8,
+    U4 => U5, U4 => U6, U4 => U7, U4 => U8,
+    U5 => U6, U5 => U7, U5 => U8,
+    U6 => U7, U6 => U8,
+    U7 => U8,
+);
@@ -5,15 +5,42 @@
 use core::{fmt, str::FromStr};
 use decimal64::ScaleMetrics;
 
-use crate::{DecimalU64, SignedDecimalU64};
+use crate::parse::ParseOptions;
+use crate::SignedDecimalU64;
 
 /// Errors for arithmetic operations (used by fallible APIs).
+///
+/// Non-exhaustive: new failure modes may be added without a breaking release.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum MathError {
     /// Division by zero.
     DivisionByZero,
-    /// Overflow/underflow in magnitude arithmetic.
+    /// The result is above the largest representable value (`+u64::MAX` unscaled).
     Overflow,
+    /// The result is below the smallest representable value (`-u64::MAX`
+    /// unscaled): overflow in the negative direction.
+    NegativeOverflow,
+    /// The result is not exactly representable in the target scale.
+    PrecisionLoss,
+    /// The operation is only defined for non-negative values.
+    NegativeNotAllowed,
+    /// A float input was NaN or infinite.
+    NonFinite,
+    /// Inputs that are paired up item by item have different lengths.
+    LengthMismatch,
+}
+
+impl MathError {
+    /// The out-of-range error for a result with the given sign.
+    #[inline]
+    pub(crate) const fn out_of_range(negative: bool) -> Self {
+        if negative {
+            MathError::NegativeOverflow
+        } else {
+            MathError::Overflow
+        }
+    }
 }
 
 impl fmt::Display for MathError {
@@ -21,50 +48,79 @@ impl fmt::Display for MathError {
         match self {
             MathError::DivisionByZero => f.write_str("division by zero"),
             MathError::Overflow => f.write_str("overflow"),
+            MathError::NegativeOverflow => f.write_str("negative overflow"),
+            MathError::PrecisionLoss => f.write_str("precision loss"),
+            MathError::NegativeNotAllowed => f.write_str("negative value not allowed"),
+            MathError::NonFinite => f.write_str("float is NaN or infinite"),
+            MathError::LengthMismatch => f.write_str("inputs have different lengths"),
         }
     }
 }
 
+impl core::error::Error for MathError {}
+
 /// Error returned when parsing a `SignedDecimalU64<S>` from a string.
+///
+/// Byte offsets refer to the original input, before any trimming.
+///
+/// Non-exhaustive: new failure modes may be added without a breaking release.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ParseSignedDecimalError {
     /// Empty or only a sign.
     Empty,
-    /// The magnitude failed to parse for the fixed scale `S`.
-    InvalidMagnitude,
+    /// Separators but no digits, e.g. `"."` or `"-."`.
+    MissingDigits,
+    /// A character that is not allowed here, at byte offset `index`.
+    InvalidCharacter { index: usize, ch: char },
+    /// More than one sign or negative marker.
+    MultipleSigns,
+    /// An opening parenthesis without a closing one, or vice versa.
+    UnbalancedParenthesis,
+    /// A group separator outside a group of three digits, or a second
+    /// decimal separator, at byte offset `index`.
+    MisplacedSeparator { index: usize },
+    /// More fraction digits than the scale `S` holds.
+    TooManyFractionalDigits { max: u32, found: u32 },
+    /// The magnitude is above `u64::MAX` units of `10^-S`.
+    Overflow,
 }
 
 impl fmt::Display for ParseSignedDecimalError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ParseSignedDecimalError::Empty => f.write_str("empty string"),
-            ParseSignedDecimalError::InvalidMagnitude => {
-                f.write_str("invalid decimal literal for this fixed scale")
+            ParseSignedDecimalError::MissingDigits => f.write_str("no digits"),
+            ParseSignedDecimalError::InvalidCharacter { index, ch } => {
+                write!(f, "invalid character {ch:?} at byte {index}")
+            }
+            ParseSignedDecimalError::MultipleSigns => f.write_str("more than one sign"),
+            ParseSignedDecimalError::UnbalancedParenthesis => f.write_str("unbalanced parenthesis"),
+            ParseSignedDecimalError::MisplacedSeparator { index } => {
+                write!(f, "misplaced separator at byte {index}")
+            }
+            ParseSignedDecimalError::TooManyFractionalDigits { max, found } => {
+                write!(f, "{found} fractional digits, at most {max} allowed")
+            }
+            ParseSignedDecimalError::Overflow => {
+                f.write_str("value out of range for this fixed scale")
             }
         }
     }
 }
 
+impl core::error::Error for ParseSignedDecimalError {}
+
 pub type Result<T> = core::result::Result<T, MathError>;
 
+/// Plain `[+-]digits.digits`, surrounding whitespace ignored; the same as
+/// `SignedDecimalU64::parse_with` with default `ParseOptions`. More than `S`
+/// fraction digits is an error; see `from_str_round` and `from_str_exact`.
 impl<S: ScaleMetrics> FromStr for SignedDecimalU64<S> {
     type Err = ParseSignedDecimalError;
 
+    #[inline]
     fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
-        let s = s.trim();
-        if s.is_empty() {
-            return Err(ParseSignedDecimalError::Empty);
-        }
-        let (neg, rest) = match s.as_bytes()[0] {
-            b'+' => (false, &s[1..]),
-            b'-' => (true, &s[1..]),
-            _ => (false, s),
-        };
-        if rest.is_empty() {
-            return Err(ParseSignedDecimalError::Empty);
-        }
-        let mag = DecimalU64::<S>::from_str(rest)
-            .map_err(|_| ParseSignedDecimalError::InvalidMagnitude)?;
-        Ok(SignedDecimalU64::new(neg, mag))
+        Self::parse_with(s, &ParseOptions::new())
     }
 }
@@ -0,0 +1,177 @@
+//! Conversions between `SignedDecimalU64<S>` and binary floats.
+//!
+//! Floats convert in from their exact binary value with an explicit
+//! `round::RoundingMode`, so `from_f64(0.1, _)` sees
+//! `0.1000000000000000055511151231257827...` and not the shortest literal.
+//! Conversions out return the nearest float, ties to even.
+
+use core::convert::TryFrom;
+use core::str::FromStr;
+
+use decimal64::ScaleMetrics;
+
+use crate::error::{MathError, Result};
+use crate::round::{should_increment, RoundingMode};
+use crate::{from_unscaled, SignedDecimalU64};
+
+impl<S: ScaleMetrics> SignedDecimalU64<S> {
+    /// The nearest `f64`, ties to even.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{sdec, U2};
+    /// assert_eq!(sdec!(U2, "-0.10").to_f64(), -0.1);
+    /// ```
+    pub fn to_f64(self) -> f64 {
+        parse_float(&self)
+    }
+
+    /// The nearest `f32`, ties to even (rounded once, not via `f64`).
+    pub fn to_f32(self) -> f32 {
+        parse_float(&self)
+    }
+
+    /// Convert the exact value of `v`, rounded to scale `S` with `mode`.
+    ///
+    /// Returns `NonFinite` for NaN and infinities, and `Overflow` /
+    /// `NegativeOverflow` if the rounded value is out of range. Negative zero
+    /// becomes zero.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{round::RoundingMode, SignedDecimalU64, U2};
+    /// let x = SignedDecimalU64::<U2>::from_f64(0.125, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(x.to_string(), "0.12");
+    /// // 0.1 is slightly above one tenth in binary.
+    /// let x = SignedDecimalU64::<U2>::from_f64(0.1, RoundingMode::Ceil).unwrap();
+    /// assert_eq!(x.to_string(), "0.11");
+    /// ```
+    pub fn from_f64(v: f64, mode: RoundingMode) -> Result<Self> {
+        let (negative, q, r, unit) = scaled_parts(v, S::SCALE_FACTOR)?;
+        let q = q + should_increment(q, r, unit, negative, mode) as u128;
+        let q = u64::try_from(q).map_err(|_| MathError::out_of_range(negative))?;
+        Ok(Self::new(negative, from_unscaled(q)))
+    }
+
+    /// Convert `v` only if its exact binary value has at most `S` decimal
+    /// places; otherwise returns `PrecisionLoss`. Other errors are as for
+    /// `from_f64`.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{error::MathError, SignedDecimalU64, U2};
+    /// assert!(SignedDecimalU64::<U2>::from_f64_exact(-1.25).is_ok());
+    /// assert_eq!(
+    ///     SignedDecimalU64::<U2>::from_f64_exact(0.1),
+    ///     Err(MathError::PrecisionLoss)
+    /// );
+    /// ```
+    pub fn from_f64_exact(v: f64) -> Result<Self> {
+        let (negative, q, r, _) = scaled_parts(v, S::SCALE_FACTOR)?;
+        if r != 0 {
+            return Err(MathError::PrecisionLoss);
+        }
+        let q = u64::try_from(q).map_err(|_| MathError::out_of_range(negative))?;
+        Ok(Self::new(negative, from_unscaled(q)))
+    }
+
+    /// Convert the exact value of `v`; see `from_f64`.
+    pub fn from_f32(v: f32, mode: RoundingMode) -> Result<Self> {
+        // Widening to f64 is exact.
+        Self::from_f64(v as f64, mode)
+    }
+
+    /// Convert `v` only if it is exact at scale `S`; see `from_f64_exact`.
+    pub fn from_f32_exact(v: f32) -> Result<Self> {
+        Self::from_f64_exact(v as f64)
+    }
+}
+
+/// Rounds to the nearest value, ties to even; see `SignedDecimalU64::from_f64`.
+impl<S: ScaleMetrics> TryFrom<f64> for SignedDecimalU64<S> {
+    type Error = MathError;
+    #[inline]
+    fn try_from(value: f64) -> Result<Self> {
+        Self::from_f64(value, RoundingMode::HalfEven)
+    }
+}
+
+/// Rounds to the nearest value, ties to even; see `SignedDecimalU64::from_f32`.
+impl<S: ScaleMetrics> TryFrom<f32> for SignedDecimalU64<S> {
+    type Error = MathError;
+    #[inline]
+    fn try_from(value: f32) -> Result<Self> {
+        Self::from_f32(value, RoundingMode::HalfEven)
+    }
+}
+
+impl<S: ScaleMetrics> From<SignedDecimalU64<S>> for f64 {
+    #[inline]
+    fn from(value: SignedDecimalU64<S>) -> Self {
+        value.to_f64()
+    }
+}
+
+impl<S: ScaleMetrics> From<SignedDecimalU64<S>> for f32 {
+    #[inline]
+    fn from(value: SignedDecimalU64<S>) -> Self {
+        value.to_f32()
+    }
+}
+
+// ---------- helpers ----------
+
+/// Parse `±<unscaled>e-<S>` with `core`'s correctly rounded float parser.
+fn parse_float<S: ScaleMetrics, F: FromStr>(x: &SignedDecimalU64<S>) -> F {
+    // Sign, up to 20 digits, then `e-` and a single exponent digit.
+    let mut buf = [0u8; 24];
+    let mut i = buf.len() - 3;
+    buf[i..].copy_from_slice(&[b'e', b'-', b'0' + S::SCALE]);
+    let mut mag = x.unscaled();
+    loop {
+        i -= 1;
+        buf[i] = b'0' + (mag % 10) as u8;
+        mag /= 10;
+        if mag == 0 {
+            break;
+        }
+    }
+    if x.is_negative() {
+        i -= 1;
+        buf[i] = b'-';
+    }
+    match core::str::from_utf8(&buf[i..]).ok().map(str::parse) {
+        Some(Ok(v)) => v,
+        _ => unreachable!("float literal is well formed"),
+    }
+}
+
+/// Split `|v| · 10^S` into `(negative, q, r, unit)`: the truncated integer
+/// part `q` and the discarded fraction `r / unit`, ready for `should_increment`.
+fn scaled_parts(v: f64, scale_factor: u64) -> Result<(bool, u128, u128, u128)> {
+    if !v.is_finite() {
+        return Err(MathError::NonFinite);
+    }
+    let negative = v.is_sign_negative();
+    let bits = v.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    // |v| = m · 2^e exactly; subnormals have no implicit leading bit.
+    let (m, e) = if biased == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | 1 << 52, biased - 1075)
+    };
+    // m · 10^S < 2^53 · 2^27.
+    let n = m as u128 * scale_factor as u128;
+    if e >= 0 {
+        if n > (u64::MAX as u128) >> e.min(64) {
+            return Err(MathError::out_of_range(negative));
+        }
+        return Ok((negative, n << e, 0, 1));
+    }
+    let shift = e.unsigned_abs();
+    if shift > 100 {
+        // Below 2^-20: any non-zero remainder is under half a unit.
+        return Ok((negative, 0, (n != 0) as u128, 4));
+    }
+    let unit = 1u128 << shift;
+    Ok((negative, n >> shift, n & (unit - 1), unit))
+}
@@ -0,0 +1,268 @@
+//! Locale-aware and accounting-style formatting.
+//!
+//! A `FormatSpec` describes the separators, the negative style, currency
+//! affixes and how many fraction digits to print. It writes straight into any
+//! `fmt::Write` without allocating, so it works under `no_std`, and
+//! `FormatSpec::display` wraps a value in a `Display` adapter.
+//!
+//! ```rust
+//! # use signed_decimal64::{format::{FormatSpec, NegativeStyle}, sdec, U2};
+//! let x = sdec!(U2, "-1234567.89");
+//! let us = FormatSpec::new().group_separator(',');
+//! assert_eq!(us.display(&x).to_string(), "-1,234,567.89");
+//! let eu = FormatSpec::new().group_separator('.').decimal_separator(',');
+//! assert_eq!(eu.display(&x).to_string(), "-1.234.567,89");
+//! let books = us.negative_style(NegativeStyle::Parentheses).prefix("$");
+//! assert_eq!(books.display(&x).to_string(), "($1,234,567.89)");
+//! ```
+
+use core::fmt::{self, Write};
+
+use decimal64::ScaleMetrics;
+
+use crate::round::{div_round, RoundingMode};
+use crate::{pow10_u64, SignedDecimalU64};
+
+/// How `FormatSpec` marks negative values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NegativeStyle {
+    /// A leading minus sign, before any currency prefix: `-$12.34`.
+    #[default]
+    Minus,
+    /// Accounting parentheses around the whole value: `($12.34)`.
+    Parentheses,
+    /// A trailing minus sign, after any currency suffix: `12.34 EUR-`.
+    TrailingMinus,
+}
+
+/// Builder describing how to print a `SignedDecimalU64<S>`.
+///
+/// By default there is no grouping, the decimal separator is `.`, negatives
+/// get a leading `-`, there are no affixes, and all `S` fraction digits are
+/// printed. Values that round to zero are printed without a negative marker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatSpec<'a> {
+    group_separator: Option<char>,
+    decimal_separator: char,
+    negative_style: NegativeStyle,
+    prefix: &'a str,
+    suffix: &'a str,
+    min_fraction_digits: Option<u32>,
+    max_fraction_digits: Option<u32>,
+    rounding: RoundingMode,
+}
+
+impl Default for FormatSpec<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> FormatSpec<'a> {
+    /// The default spec: `-1234567.89` at `U2`.
+    pub const fn new() -> Self {
+        Self {
+            group_separator: None,
+            decimal_separator: '.',
+            negative_style: NegativeStyle::Minus,
+            prefix: "",
+            suffix: "",
+            min_fraction_digits: None,
+            max_fraction_digits: None,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Separate groups of three integer digits with `sep`.
+    pub const fn group_separator(mut self, sep: char) -> Self {
+        self.group_separator = Some(sep);
+        self
+    }
+
+    /// Use `sep` between the integer and fraction digits.
+    pub const fn decimal_separator(mut self, sep: char) -> Self {
+        self.decimal_separator = sep;
+        self
+    }
+
+    /// Mark negative values with `style`.
+    pub const fn negative_style(mut self, style: NegativeStyle) -> Self {
+        self.negative_style = style;
+        self
+    }
+
+    /// Print `prefix` (e.g. a currency symbol) right before the digits.
+    pub const fn prefix(mut self, prefix: &'a str) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Print `suffix` (e.g. `" EUR"`) right after the digits.
+    pub const fn suffix(mut self, suffix: &'a str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    /// Print at least `n` fraction digits, padding with zeros; trailing zeros
+    /// beyond `n` are dropped. Defaults to the scale `S`.
+    pub const fn min_fraction_digits(mut self, n: u32) -> Self {
+        self.min_fraction_digits = Some(n);
+        self
+    }
+
+    /// Print at most `n` fraction digits, rounding the rest away with the
+    /// spec's rounding mode. Defaults to the larger of the scale `S` and
+    /// `min_fraction_digits`, and caps the latter.
+    pub const fn max_fraction_digits(mut self, n: u32) -> Self {
+        self.max_fraction_digits = Some(n);
+        self
+    }
+
+    /// Round with `mode` when `max_fraction_digits` drops digits
+    /// (default `HalfEven`).
+    pub const fn rounding(mut self, mode: RoundingMode) -> Self {
+        self.rounding = mode;
+        self
+    }
+
+    /// Wrap `value` in a `Display` adapter. Width, fill and alignment from
+    /// the formatter pad the whole formatted text.
+    pub const fn display<'b, S: ScaleMetrics>(
+        &self,
+        value: &'b SignedDecimalU64<S>,
+    ) -> Formatted<'b, S>
+    where
+        'a: 'b,
+    {
+        Formatted { spec: *self, value }
+    }
+
+    /// Write `value` into `out`.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{format::FormatSpec, sdec, U4};
+    /// let spec = FormatSpec::new().min_fraction_digits(2).max_fraction_digits(3);
+    /// let mut out = String::new();
+    /// spec.write_to(&sdec!(U4, "7.5000"), &mut out).unwrap();
+    /// out.push(' ');
+    /// spec.write_to(&sdec!(U4, "0.1234"), &mut out).unwrap();
+    /// assert_eq!(out, "7.50 0.123");
+    /// ```
+    pub fn write_to<S: ScaleMetrics, W: Write + ?Sized>(
+        &self,
+        value: &SignedDecimalU64<S>,
+        out: &mut W,
+    ) -> fmt::Result {
+        let scale = S::SCALE as u32;
+        let min = self.min_fraction_digits.unwrap_or(scale);
+        let max = self.max_fraction_digits.unwrap_or(scale.max(min));
+        let min = min.min(max);
+        let mut digits = max.min(scale);
+
+        let mut mag = value.unscaled();
+        if digits < scale {
+            // Dropping at least one digit leaves room to round up within u64.
+            let unit = pow10_u64(scale - digits) as u128;
+            mag = div_round(mag as u128, unit, value.is_negative(), self.rounding)
+                .expect("rounded magnitude fits");
+        }
+        let negative = value.is_negative() && mag != 0;
+        let unit = pow10_u64(digits);
+        let (int, mut frac) = (mag / unit, mag % unit);
+        while digits > min && frac % 10 == 0 {
+            frac /= 10;
+            digits -= 1;
+        }
+        let zeros = min.saturating_sub(digits);
+
+        match (negative, self.negative_style) {
+            (true, NegativeStyle::Minus) => out.write_char('-')?,
+            (true, NegativeStyle::Parentheses) => out.write_char('(')?,
+            _ => {}
+        }
+        out.write_str(self.prefix)?;
+
+        let mut buf = [0u8; 20];
+        let int_digits = write_digits(&mut buf, int, 1);
+        for (i, &d) in int_digits.iter().enumerate() {
+            if let Some(sep) = self.group_separator {
+                if i > 0 && (int_digits.len() - i) % 3 == 0 {
+                    out.write_char(sep)?;
+                }
+            }
+            out.write_char(d as char)?;
+        }
+        if digits + zeros > 0 {
+            out.write_char(self.decimal_separator)?;
+            if digits > 0 {
+                let frac_digits = write_digits(&mut buf, frac, digits as usize);
+                out.write_str(core::str::from_utf8(frac_digits).map_err(|_| fmt::Error)?)?;
+            }
+            for _ in 0..zeros {
+                out.write_char('0')?;
+            }
+        }
+
+        out.write_str(self.suffix)?;
+        match (negative, self.negative_style) {
+            (true, NegativeStyle::Parentheses) => out.write_char(')'),
+            (true, NegativeStyle::TrailingMinus) => out.write_char('-'),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// `Display` adapter returned by `FormatSpec::display`.
+#[derive(Debug)]
+pub struct Formatted<'a, S: ScaleMetrics> {
+    spec: FormatSpec<'a>,
+    value: &'a SignedDecimalU64<S>,
+}
+
+impl<S: ScaleMetrics> fmt::Display for Formatted<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(width) = f.width() else {
+            return self.spec.write_to(self.value, f);
+        };
+        let mut counter = CharCount(0);
+        self.spec.write_to(self.value, &mut counter)?;
+        let pad = width.saturating_sub(counter.0);
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Left) => (0, pad),
+            Some(fmt::Alignment::Center) => (pad / 2, pad - pad / 2),
+            _ => (pad, 0),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        self.spec.write_to(self.value, f)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+// ---------- helpers ----------
+
+/// Counts the characters written, for padding.
+struct CharCount(usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// The decimal digits of `n`, zero-padded to at least `width`, as a suffix of `buf`.
+fn write_digits(buf: &mut [u8; 20], mut n: u64, width: usize) -> &[u8] {
+    let mut start = buf.len();
+    while n > 0 || buf.len() - start < width {
+        start -= 1;
+        buf[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+    }
+    &buf[start..]
+}
@@ -0,0 +1,66 @@
+//! Fallible aggregation over iterators of `SignedDecimalU64<S>`.
+//!
+//! The `Sum`/`Product` impls in `arithmetic` panic on overflow, like the
+//! operators they fold with. `SignedDecimalIterExt` offers checked, `Result`
+//! and saturating variants instead, and `Option<SignedDecimalU64<S>>`
+//! implements `Sum` so `iter.sum::<Option<_>>()` never panics.
+
+use core::iter::Sum;
+use decimal64::ScaleMetrics;
+
+use crate::arithmetic::deref_copy;
+use crate::error::Result;
+use crate::SignedDecimalU64;
+
+/// Extension methods for iterators yielding `SignedDecimalU64<S>`.
+///
+/// ```rust
+/// # use signed_decimal64::{iter::SignedDecimalIterExt, sdec, SignedDecimalU64, U2};
+/// let fills = [sdec!(U2, "10.50"), sdec!(U2, "-2.25")];
+/// assert_eq!(fills.iter().copied().checked_sum(), Some(sdec!(U2, "8.25")));
+/// let big = [SignedDecimalU64::<U2>::MAX, SignedDecimalU64::ONE];
+/// assert_eq!(big.iter().copied().checked_sum(), None);
+/// assert_eq!(big.iter().copied().saturating_sum(), SignedDecimalU64::MAX);
+/// ```
+pub trait SignedDecimalIterExt<S: ScaleMetrics>:
+    Iterator<Item = SignedDecimalU64<S>> + Sized
+{
+    /// Sum the items, returning `None` as soon as the running total overflows.
+    fn checked_sum(mut self) -> Option<SignedDecimalU64<S>> {
+        self.try_fold(SignedDecimalU64::ZERO, SignedDecimalU64::checked_add)
+    }
+
+    /// Sum the items, returning `Overflow`/`NegativeOverflow` as soon as the running
+    /// total leaves the representable range.
+    fn try_sum(mut self) -> Result<SignedDecimalU64<S>> {
+        self.try_fold(SignedDecimalU64::ZERO, SignedDecimalU64::try_add)
+    }
+
+    /// Multiply the items (truncating toward zero at each step), returning
+    /// `None` as soon as the running product overflows.
+    fn checked_product(mut self) -> Option<SignedDecimalU64<S>> {
+        self.try_fold(SignedDecimalU64::ONE, SignedDecimalU64::checked_mul)
+    }
+
+    /// Sum the items with `saturating_add`: the running total is clamped to
+    /// `MIN`/`MAX` and never fails.
+    fn saturating_sum(self) -> SignedDecimalU64<S> {
+        self.fold(SignedDecimalU64::ZERO, SignedDecimalU64::saturating_add)
+    }
+}
+
+impl<S: ScaleMetrics, I: Iterator<Item = SignedDecimalU64<S>>> SignedDecimalIterExt<S> for I {}
+
+/// `None` if the running total overflows.
+impl<S: ScaleMetrics> Sum<SignedDecimalU64<S>> for Option<SignedDecimalU64<S>> {
+    fn sum<I: Iterator<Item = SignedDecimalU64<S>>>(iter: I) -> Self {
+        iter.checked_sum()
+    }
+}
+
+/// `None` if the running total overflows.
+impl<'a, S: ScaleMetrics> Sum<&'a SignedDecimalU64<S>> for Option<SignedDecimalU64<S>> {
+    fn sum<I: Iterator<Item = &'a SignedDecimalU64<S>>>(iter: I) -> Self {
+        iter.map(deref_copy).checked_sum()
+    }
+}
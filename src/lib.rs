@@ -5,6 +5,14 @@
 //!
 //! Modules:
 //! - `arithmetic`: operators + checked_* helpers
+//! - `iter`: checked / fallible / saturating sums over iterators
+//! - `accumulator`: exact `i128`-backed summation of many values
+//! - `stats`: exact mean, weighted mean and VWAP with a single final rounding
+//! - `allocate` (feature = "alloc"): total-preserving pro-rata splits
+//! - `pow`: powers, roots, `exp` and logarithms with a single final rounding
+//! - `format`: locale-aware and accounting-style formatting via `FormatSpec`
+//! - `parse`: lenient and locale-aware parsing via `ParseOptions`
+//! - `float`: correctly rounded conversions to and from `f64` / `f32`
 //! - `round`: rounding utilities and cross-scale conversion
 //! - `serde` (feature = "serde"): Serialize/Deserialize impls
 //! - `macros`: `sdec!` and `sdec_unscaled!`
@@ -16,6 +24,9 @@
 #![forbid(unsafe_code)]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::cmp::Ordering;
 use core::fmt;
 
@@ -155,6 +166,104 @@ impl<S: ScaleMetrics> SignedDecimalU64<S> {
         mag: DecimalU64::<S>::ONE,
     };
 
+    /// Largest representable value (`+u64::MAX` unscaled).
+    pub const MAX: Self = Self {
+        negative: false,
+        mag: DecimalU64::<S>::MAX,
+    };
+
+    /// Smallest representable value (`-u64::MAX` unscaled).
+    pub const MIN: Self = Self {
+        negative: true,
+        mag: DecimalU64::<S>::MAX,
+    };
+
+    /// Smallest positive value: one unit in the last place of scale `S`.
+    pub const EPSILON: Self = Self {
+        negative: false,
+        mag: DecimalU64::<S>::from_raw(1),
+    };
+
+    /// Minus one constant (value `-1` in the fixed scale `S`).
+    pub const NEG_ONE: Self = Self {
+        negative: true,
+        mag: DecimalU64::<S>::ONE,
+    };
+
+    /// Two constant (value `2` in the fixed scale `S`).
+    pub const TWO: Self = Self {
+        negative: false,
+        mag: DecimalU64::<S>::TWO,
+    };
+
+    /// Ten constant (value `10` in the fixed scale `S`).
+    pub const TEN: Self = Self {
+        negative: false,
+        mag: DecimalU64::<S>::TEN,
+    };
+
+    /// One hundred constant (value `100` in the fixed scale `S`).
+    pub const ONE_HUNDRED: Self = Self {
+        negative: false,
+        mag: DecimalU64::<S>::from_raw(100 * S::SCALE_FACTOR),
+    };
+
+    /// One unit in the last place for scale `S` (`10^-S`); same as `EPSILON`.
+    #[inline]
+    pub const fn ulp() -> Self {
+        Self::EPSILON
+    }
+
+    /// Largest whole number representable at scale `S` (`u64::MAX / 10^S`).
+    ///
+    /// Any value with a larger integer part in magnitude is out of range.
+    #[inline]
+    pub const fn max_integer_part() -> u64 {
+        u64::MAX / S::SCALE_FACTOR
+    }
+
+    /// Integer part, truncated toward zero and keeping the sign:
+    /// `int_part(-1.25) == -1.00`.
+    #[inline]
+    pub const fn int_part(self) -> Self {
+        let unit = pow10_u64(S::SCALE as u32);
+        Self::new(
+            self.is_negative(),
+            from_unscaled(self.mag.unscaled / unit * unit),
+        )
+    }
+
+    /// Fractional part, keeping the sign: `fract(-1.25) == -0.25`, so that
+    /// `x == x.int_part() + x.fract()`.
+    #[inline]
+    pub const fn fract(self) -> Self {
+        Self::new(self.is_negative(), from_unscaled(self.fract_unscaled()))
+    }
+
+    /// Fractional digits as an unsigned integer at scale `S`, ignoring the
+    /// sign: `25` for `-1.25` at `U2`.
+    #[inline]
+    pub const fn fract_unscaled(&self) -> u64 {
+        self.mag.unscaled % pow10_u64(S::SCALE as u32)
+    }
+
+    /// Number of digits needed to write the integer part, ignoring the sign.
+    /// Values below one in magnitude count the single `0`: `1` for `-0.25`,
+    /// `3` for `-123.45`.
+    #[inline]
+    pub const fn integer_digits(&self) -> u32 {
+        match self.mag.unscaled / pow10_u64(S::SCALE as u32) {
+            0 => 1,
+            n => n.ilog10() + 1,
+        }
+    }
+
+    /// Returns `true` if the value has no fractional part.
+    #[inline]
+    pub const fn is_integer(&self) -> bool {
+        self.fract_unscaled() == 0
+    }
+
     /// Always return the underlying magnitude (drops sign if negative).
     /// No panic.
     #[inline]
@@ -219,14 +328,143 @@ impl<S: ScaleMetrics> From<SignedDecimalU64<S>> for (bool, DecimalU64<S>) {
 
 // --- Formatting ---
 
+/// Prints all `S` fractional digits (`-1.50` at `U2`) and honors the
+/// formatter's width, fill, alignment (right by default), `+` and `0` flags.
+/// A precision rounds half-to-even or pads with zeros to that many decimals;
+/// a negative value that rounds to zero prints without a sign, so there is
+/// no `-0`.
+///
+/// ```rust
+/// # use signed_decimal64::{sdec, U4};
+/// let x = sdec!(U4, "-12.3450");
+/// assert_eq!(format!("{x:.2}"), "-12.34");
+/// assert_eq!(format!("{x:>10.1}"), "     -12.3");
+/// assert_eq!(format!("{:+}", -x), "+12.3450");
+/// assert_eq!(format!("{x:*^12}"), "**-12.3450**");
+/// ```
 impl<S: ScaleMetrics> fmt::Display for SignedDecimalU64<S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.is_negative() {
-            write!(f, "-{}", &self.mag)
-        } else {
-            write!(f, "{}", &self.mag)
+        write_decimal(self, f, false)
+    }
+}
+
+/// Display adapter that drops trailing fractional zeros and a bare decimal
+/// point: `12.5000` at `U4` prints as `12.5`, and `3.0000` as `3`.
+///
+/// Formatter flags apply as for `Display`; a precision caps the number of
+/// decimals before trimming. Created by `SignedDecimalU64::trim_trailing_zeros`.
+#[derive(Debug)]
+pub struct TrimTrailingZeros<'a, S: ScaleMetrics>(&'a SignedDecimalU64<S>);
+
+impl<S: ScaleMetrics> SignedDecimalU64<S> {
+    /// Display without trailing fractional zeros; see `TrimTrailingZeros`.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{sdec, U4};
+    /// assert_eq!(sdec!(U4, "12.50").trim_trailing_zeros().to_string(), "12.5");
+    /// ```
+    #[inline]
+    pub fn trim_trailing_zeros(&self) -> TrimTrailingZeros<'_, S> {
+        TrimTrailingZeros(self)
+    }
+}
+
+impl<S: ScaleMetrics> fmt::Display for TrimTrailingZeros<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_decimal(self.0, f, true)
+    }
+}
+
+/// Shared body of the `Display` impls: render into a stack buffer, then pad.
+fn write_decimal<S: ScaleMetrics>(
+    x: &SignedDecimalU64<S>,
+    f: &mut fmt::Formatter<'_>,
+    trim: bool,
+) -> fmt::Result {
+    use core::fmt::Write;
+
+    let neg = x.is_negative();
+    let mut mag = x.unscaled();
+    let mut dp = S::SCALE as usize;
+    if let Some(p) = f.precision().filter(|&p| p < dp) {
+        // Dropping at least one digit leaves room to round up within u64.
+        let unit = pow10_u64((dp - p) as u32) as u128;
+        mag = round::div_round(mag as u128, unit, neg, round::RoundingMode::HalfEven)
+            .expect("rounded magnitude fits");
+        dp = p;
+    }
+    let neg = neg && mag != 0;
+    let zeros = match f.precision() {
+        Some(p) if !trim => p - dp,
+        _ => 0,
+    };
+
+    // Up to 20 digits, a decimal point and a leading zero.
+    let mut buf = [0u8; 22];
+    let mut start = buf.len();
+    for i in 0.. {
+        if i == dp && dp > 0 {
+            start -= 1;
+            buf[start] = b'.';
+        }
+        if i > dp && mag == 0 {
+            break;
+        }
+        start -= 1;
+        buf[start] = b'0' + (mag % 10) as u8;
+        mag /= 10;
+    }
+    let mut end = buf.len();
+    if trim && dp > 0 {
+        while buf[end - 1] == b'0' {
+            end -= 1;
+        }
+        if buf[end - 1] == b'.' {
+            end -= 1;
+        }
+    }
+    let body = core::str::from_utf8(&buf[start..end]).map_err(|_| fmt::Error)?;
+    let point = zeros > 0 && dp == 0;
+
+    let sign = if neg {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let len = sign.len() + body.len() + point as usize + zeros;
+    let pad = f.width().unwrap_or(0).saturating_sub(len);
+    let (fill, before, after) = if f.sign_aware_zero_pad() {
+        ('0', 0, 0)
+    } else {
+        match f.align() {
+            Some(fmt::Alignment::Left) => (f.fill(), 0, pad),
+            Some(fmt::Alignment::Center) => (f.fill(), pad / 2, pad - pad / 2),
+            _ => (f.fill(), pad, 0),
+        }
+    };
+
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(sign)?;
+    if f.sign_aware_zero_pad() {
+        for _ in 0..pad {
+            f.write_char('0')?;
         }
     }
+    f.write_str(body)?;
+    if point {
+        f.write_char('.')?;
+    }
+    for _ in 0..zeros {
+        f.write_char('0')?;
+    }
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
 }
 
 // --- Eq/Hash with -0 == 0 ---
@@ -284,41 +522,122 @@ impl<S: ScaleMetrics> core::ops::Neg for SignedDecimalU64<S> {
 
 // Public prelude for convenience.
 pub mod prelude {
+    pub use crate::iter::SignedDecimalIterExt;
     pub use crate::{
         DecimalU64, ScaleMetrics, SignedDecimalU64, U0, U1, U2, U3, U4, U5, U6, U7, U8,
     };
 }
 
 // Submodules
+pub mod accumulator;
+#[cfg(feature = "alloc")]
+pub mod allocate;
 pub mod arithmetic;
 pub mod error;
+pub mod float;
+pub mod format;
+pub mod iter;
 pub mod macros;
+pub mod parse;
+pub mod pow;
 pub mod round;
+pub mod stats;
+mod wide;
 
 #[cfg(all(feature = "serde", feature = "alloc"))]
 pub mod serde;
 
-// Conversions from signed unscaled integers
-impl<S: ScaleMetrics> core::convert::TryFrom<i128> for SignedDecimalU64<S> {
-    type Error = crate::error::MathError;
+// --- Integer conversions ---
+//
+// `*_unscaled` constructors and the `TryFrom<integer>` impls take the raw
+// unscaled value (`150` is `1.50` at `U2`); `*_int` constructors and the
+// `to_i64*` accessors work in whole units (`1` is `1.00` at `U2`).
+
+impl<S: ScaleMetrics> SignedDecimalU64<S> {
+    /// From a signed *unscaled* integer: `150` is `1.50` at `U2`.
+    ///
+    /// Returns `Overflow` / `NegativeOverflow` if the magnitude exceeds `u64::MAX`.
     #[inline]
-    fn try_from(value: i128) -> Result<Self, Self::Error> {
-        let neg = value.is_negative();
-        let abs = value
-            .checked_abs()
-            .ok_or(crate::error::MathError::Overflow)? as u128;
+    pub const fn try_from_unscaled(unscaled: i128) -> Result<Self, crate::error::MathError> {
+        let neg = unscaled.is_negative();
+        let abs = unscaled.unsigned_abs();
         if abs > (u64::MAX as u128) {
-            return Err(crate::error::MathError::Overflow);
+            return Err(crate::error::MathError::out_of_range(neg));
         }
-        let mag = DecimalU64::<S>::from_raw(abs as u64);
-        Ok(SignedDecimalU64::new(neg, mag))
+        Ok(SignedDecimalU64::new(
+            neg,
+            DecimalU64::<S>::from_raw(abs as u64),
+        ))
+    }
+
+    /// From a whole number of units: `3` is `3.00` at `U2`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is outside `±max_integer_part()`.
+    #[inline]
+    pub fn from_int(value: i64) -> Self {
+        Self::try_from_int(value as i128).expect("SignedDecimalU64::from_int overflow")
     }
-}
 
-impl<S: ScaleMetrics> core::convert::TryFrom<i64> for SignedDecimalU64<S> {
-    type Error = crate::error::MathError;
+    /// From a whole number of units: `3` is `3.00` at `U2`.
+    ///
+    /// Returns `Overflow` / `NegativeOverflow` if `value · 10^S` is out of range.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{SignedDecimalU64, U2};
+    /// let x = SignedDecimalU64::<U2>::try_from_int(-3).unwrap();
+    /// assert_eq!(x.to_string(), "-3.00");
+    /// ```
     #[inline]
-    fn try_from(value: i64) -> Result<Self, Self::Error> {
-        SignedDecimalU64::<S>::try_from(value as i128)
+    pub fn try_from_int(value: i128) -> Result<Self, crate::error::MathError> {
+        let neg = value.is_negative();
+        value
+            .unsigned_abs()
+            .checked_mul(S::SCALE_FACTOR as u128)
+            .and_then(|abs| u64::try_from(abs).ok())
+            .map(|abs| SignedDecimalU64::new(neg, DecimalU64::<S>::from_raw(abs)))
+            .ok_or(crate::error::MathError::out_of_range(neg))
+    }
+
+    /// Whole units, truncated toward zero; `None` if they don't fit in `i64`.
+    #[inline]
+    pub fn to_i64_trunc(self) -> Option<i64> {
+        self.to_i64(round::RoundingMode::TowardZero)
+    }
+
+    /// Whole units, rounded with `mode`; `None` if they don't fit in `i64`.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{round::RoundingMode, sdec, U2};
+    /// assert_eq!(sdec!(U2, "-2.50").to_i64(RoundingMode::HalfEven), Some(-2));
+    /// assert_eq!(sdec!(U2, "-2.50").to_i64(RoundingMode::Floor), Some(-3));
+    /// ```
+    #[inline]
+    pub fn to_i64(self, mode: round::RoundingMode) -> Option<i64> {
+        let neg = self.is_negative();
+        let whole =
+            round::div_round(self.unscaled() as u128, S::SCALE_FACTOR as u128, neg, mode)? as i128;
+        i64::try_from(if neg { -whole } else { whole }).ok()
     }
 }
+
+macro_rules! impl_try_from_unscaled_int {
+    ($($t:ty),* $(,)?) => {$(
+        /// Treats the integer as the *unscaled* value (`150` is `1.50` at
+        /// `U2`); use `try_from_int` for whole units.
+        impl<S: ScaleMetrics> core::convert::TryFrom<$t> for SignedDecimalU64<S> {
+            type Error = crate::error::MathError;
+            #[inline]
+            fn try_from(value: $t) -> Result<Self, Self::Error> {
+                match i128::try_from(value) {
+                    Ok(unscaled) => SignedDecimalU64::<S>::try_from_unscaled(unscaled),
+                    // Only `u128` values above `i128::MAX` get here.
+                    Err(_) => Err(crate::error::MathError::Overflow),
+                }
+            }
+        }
+    )*};
+}
+
+impl_try_from_unscaled_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
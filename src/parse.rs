@@ -0,0 +1,428 @@
+//! Locale-aware and lenient parsing, the counterpart of `format`.
+//!
+//! `ParseOptions` lists what an input may contain beyond plain
+//! `[+-]digits.digits`: group separators, another decimal separator,
+//! accounting parentheses, a trailing minus and currency affixes.
+//! `SignedDecimalU64::parse_with` reads such text and reports what went
+//! wrong, with byte offsets into the original input.
+//!
+//! `FromStr` and `parse_with` are strict about the scale and reject extra
+//! fraction digits; `from_str_round` rounds them away and `from_str_exact`
+//! accepts only trailing zeros.
+//!
+//! ```rust
+//! # use signed_decimal64::{parse::ParseOptions, sdec, SignedDecimalU64, U2};
+//! let us = ParseOptions::new().group_separator(',').prefix("$").allow_parentheses(true);
+//! let x = SignedDecimalU64::<U2>::parse_with("($1,234,567.89)", &us).unwrap();
+//! assert_eq!(x, sdec!(U2, "-1234567.89"));
+//!
+//! let eu = ParseOptions::new()
+//!     .group_separator('.')
+//!     .decimal_separator(',')
+//!     .suffix("EUR")
+//!     .allow_trailing_minus(true);
+//! let x = SignedDecimalU64::<U2>::parse_with("12,34 EUR-", &eu).unwrap();
+//! assert_eq!(x, sdec!(U2, "-12.34"));
+//! ```
+
+use decimal64::ScaleMetrics;
+
+use crate::error::ParseSignedDecimalError;
+use crate::round::{should_increment, RoundingMode};
+use crate::{from_unscaled, pow10_u64, SignedDecimalU64};
+
+/// Builder describing which notations `SignedDecimalU64::parse_with` accepts.
+///
+/// The defaults accept the same inputs as `FromStr`: an optional leading
+/// `+` or `-`, digits and a `.` decimal separator, with surrounding
+/// whitespace ignored. Affixes are optional in the input and may be
+/// separated from the digits by whitespace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions<'a> {
+    group_separator: Option<char>,
+    decimal_separator: char,
+    allow_parentheses: bool,
+    allow_trailing_minus: bool,
+    prefix: &'a str,
+    suffix: &'a str,
+}
+
+impl Default for ParseOptions<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> ParseOptions<'a> {
+    /// The default options: `-1234567.89` only.
+    pub const fn new() -> Self {
+        Self {
+            group_separator: None,
+            decimal_separator: '.',
+            allow_parentheses: false,
+            allow_trailing_minus: false,
+            prefix: "",
+            suffix: "",
+        }
+    }
+
+    /// Accept `sep` between groups of three integer digits. Grouping is
+    /// optional, but when present every group after the first must have
+    /// exactly three digits.
+    pub const fn group_separator(mut self, sep: char) -> Self {
+        self.group_separator = Some(sep);
+        self
+    }
+
+    /// Expect `sep` between the integer and fraction digits.
+    pub const fn decimal_separator(mut self, sep: char) -> Self {
+        self.decimal_separator = sep;
+        self
+    }
+
+    /// Read `(12.34)` as negative.
+    pub const fn allow_parentheses(mut self, allow: bool) -> Self {
+        self.allow_parentheses = allow;
+        self
+    }
+
+    /// Read `12.34-` as negative; the minus may follow the suffix.
+    pub const fn allow_trailing_minus(mut self, allow: bool) -> Self {
+        self.allow_trailing_minus = allow;
+        self
+    }
+
+    /// Skip `prefix` (e.g. a currency symbol) before the digits. A sign may
+    /// come before or after it.
+    pub const fn prefix(mut self, prefix: &'a str) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Skip `suffix` (e.g. `"EUR"`) after the digits.
+    pub const fn suffix(mut self, suffix: &'a str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+}
+
+impl<S: ScaleMetrics> SignedDecimalU64<S> {
+    /// Parse `s` as described by `opts`.
+    ///
+    /// Returns `Empty` or `MissingDigits` if there are no digits,
+    /// `InvalidCharacter` for anything `opts` does not allow, `MultipleSigns`
+    /// if the value is marked negative (or positive) twice,
+    /// `UnbalancedParenthesis`, and `MisplacedSeparator` for a misplaced
+    /// group or second decimal separator. Well-formed values with more than
+    /// `S` fraction digits are `TooManyFractionalDigits`, and those beyond
+    /// `u64::MAX` units of `10^-S` are `Overflow`.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{error::ParseSignedDecimalError, parse::ParseOptions};
+    /// # use signed_decimal64::{SignedDecimalU64, U2};
+    /// let opts = ParseOptions::new().group_separator(',');
+    /// assert!(SignedDecimalU64::<U2>::parse_with("-1,000.5", &opts).is_ok());
+    /// assert_eq!(
+    ///     SignedDecimalU64::<U2>::parse_with("10,00", &opts),
+    ///     Err(ParseSignedDecimalError::MisplacedSeparator { index: 2 })
+    /// );
+    /// ```
+    pub fn parse_with(
+        s: &str,
+        opts: &ParseOptions<'_>,
+    ) -> core::result::Result<Self, ParseSignedDecimalError> {
+        Self::parse_impl(s, opts, Excess::Reject)
+    }
+
+    /// Parse plain `[+-]digits.digits` with any number of fraction digits,
+    /// rounding to scale `S` with `mode`.
+    ///
+    /// This is the lenient counterpart of `FromStr`, which rejects extra
+    /// digits with `TooManyFractionalDigits`. Other errors are as for
+    /// `FromStr`; a value that rounds past `u64::MAX` units is `Overflow`.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{round::RoundingMode, sdec, SignedDecimalU64, U2};
+    /// let x = SignedDecimalU64::<U2>::from_str_round("-1.005", RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(x, sdec!(U2, "-1.00"));
+    /// let x = SignedDecimalU64::<U2>::from_str_round("-1.00001", RoundingMode::Floor).unwrap();
+    /// assert_eq!(x, sdec!(U2, "-1.01"));
+    /// ```
+    pub fn from_str_round(
+        s: &str,
+        mode: RoundingMode,
+    ) -> core::result::Result<Self, ParseSignedDecimalError> {
+        Self::parse_impl(s, &ParseOptions::new(), Excess::Round(mode))
+    }
+
+    /// Parse plain `[+-]digits.digits`, accepting fraction digits beyond
+    /// scale `S` only if they are all zero.
+    ///
+    /// Any non-zero digit past `S` places is `TooManyFractionalDigits`, so
+    /// the value is never altered. Other errors are as for `FromStr`.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{error::ParseSignedDecimalError, sdec, SignedDecimalU64, U2};
+    /// assert_eq!(SignedDecimalU64::<U2>::from_str_exact("1.2500"), Ok(sdec!(U2, "1.25")));
+    /// assert_eq!(
+    ///     SignedDecimalU64::<U2>::from_str_exact("1.2501"),
+    ///     Err(ParseSignedDecimalError::TooManyFractionalDigits { max: 2, found: 4 })
+    /// );
+    /// ```
+    pub fn from_str_exact(s: &str) -> core::result::Result<Self, ParseSignedDecimalError> {
+        Self::parse_impl(s, &ParseOptions::new(), Excess::Exact)
+    }
+
+    fn parse_impl(
+        s: &str,
+        opts: &ParseOptions<'_>,
+        excess: Excess,
+    ) -> core::result::Result<Self, ParseSignedDecimalError> {
+        let mut c = Cursor::new(s);
+        c.trim();
+        let mut negative = false;
+        let mut signs = 0u32;
+
+        if opts.allow_parentheses {
+            if c.eat_front('(') {
+                if !c.eat_back(')') {
+                    return Err(ParseSignedDecimalError::UnbalancedParenthesis);
+                }
+                c.trim();
+                negative = true;
+                signs += 1;
+            } else if c.rest().ends_with(')') {
+                return Err(ParseSignedDecimalError::UnbalancedParenthesis);
+            }
+        }
+        if opts.allow_trailing_minus && c.eat_back('-') {
+            negative = true;
+            signs += 1;
+        }
+        if !opts.suffix.is_empty() && c.eat_back_str(opts.suffix) {
+            c.trim();
+        }
+
+        let mut prefix_seen = opts.prefix.is_empty();
+        loop {
+            if c.eat_front('-') {
+                negative = true;
+                signs += 1;
+            } else if c.eat_front('+') {
+                signs += 1;
+            } else if !prefix_seen && c.eat_front_str(opts.prefix) {
+                prefix_seen = true;
+                c.trim();
+            } else {
+                break;
+            }
+        }
+        if signs > 1 {
+            return Err(ParseSignedDecimalError::MultipleSigns);
+        }
+
+        let mag = parse_digits(&c, opts, S::SCALE as u32)?.magnitude(negative, excess)?;
+        Ok(Self::new(negative, from_unscaled(mag)))
+    }
+}
+
+// ---------- helpers ----------
+
+/// What to do with fraction digits beyond the scale.
+#[derive(Clone, Copy)]
+enum Excess {
+    /// `TooManyFractionalDigits`.
+    Reject,
+    /// Allowed if they are all zero.
+    Exact,
+    /// Rounded away with the mode.
+    Round(RoundingMode),
+}
+
+/// A scanned number: `int.frac` at `scale` places, plus a summary of the
+/// digits past it.
+struct Digits {
+    /// `None` if the integer part overflows `u64`.
+    int: Option<u64>,
+    /// The first `scale` fraction digits, zero-padded.
+    frac: u64,
+    frac_digits: u32,
+    scale: u32,
+    /// The first fraction digit past the scale.
+    first_dropped: u32,
+    /// Whether any later digit is non-zero.
+    sticky: bool,
+}
+
+impl Digits {
+    /// The magnitude in units of `10^-scale`, handling extra digits per `excess`.
+    fn magnitude(
+        &self,
+        negative: bool,
+        excess: Excess,
+    ) -> core::result::Result<u64, ParseSignedDecimalError> {
+        let inexact = self.first_dropped != 0 || self.sticky;
+        let too_many = match excess {
+            Excess::Reject => self.frac_digits > self.scale,
+            Excess::Exact => inexact,
+            Excess::Round(_) => false,
+        };
+        if too_many {
+            return Err(ParseSignedDecimalError::TooManyFractionalDigits {
+                max: self.scale,
+                found: self.frac_digits,
+            });
+        }
+        let q = self
+            .int
+            .and_then(|n| n.checked_mul(pow10_u64(self.scale))?.checked_add(self.frac))
+            .ok_or(ParseSignedDecimalError::Overflow)?;
+        match excess {
+            // `(2d + sticky) / 20` is above, at or below half exactly when
+            // `0.d...` is.
+            Excess::Round(mode) if inexact => {
+                let r = 2 * self.first_dropped as u128 + self.sticky as u128;
+                let up = should_increment(q as u128, r, 20, negative, mode);
+                q.checked_add(up as u64)
+                    .ok_or(ParseSignedDecimalError::Overflow)
+            }
+            _ => Ok(q),
+        }
+    }
+}
+
+/// Scan the digits left in `c` at `scale` fraction places.
+fn parse_digits(
+    c: &Cursor<'_>,
+    opts: &ParseOptions<'_>,
+    scale: u32,
+) -> core::result::Result<Digits, ParseSignedDecimalError> {
+    let misplaced = |index| ParseSignedDecimalError::MisplacedSeparator { index };
+    let mut n = Digits {
+        int: Some(0),
+        frac: 0,
+        frac_digits: 0,
+        scale,
+        first_dropped: 0,
+        sticky: false,
+    };
+    let mut any_digit = false;
+    let mut decimal_at = None;
+    // The last group separator and the number of digits after it.
+    let mut group_at = None;
+    let mut group_len = 0u32;
+
+    for (index, ch) in c.char_indices() {
+        if let Some(d) = ch.to_digit(10) {
+            any_digit = true;
+            if decimal_at.is_some() {
+                n.frac_digits = n.frac_digits.saturating_add(1);
+                if n.frac_digits <= scale {
+                    n.frac = n.frac * 10 + d as u64;
+                } else if n.frac_digits == scale + 1 {
+                    n.first_dropped = d;
+                } else {
+                    n.sticky |= d != 0;
+                }
+            } else {
+                n.int = n.int.and_then(|v| v.checked_mul(10)?.checked_add(d as u64));
+                group_len += 1;
+            }
+        } else if ch == opts.decimal_separator {
+            if decimal_at.is_some() {
+                return Err(misplaced(index));
+            }
+            if let Some(at) = group_at.filter(|_| group_len != 3) {
+                return Err(misplaced(at));
+            }
+            decimal_at = Some(index);
+        } else if Some(ch) == opts.group_separator {
+            let first = group_at.is_none();
+            if decimal_at.is_some() || (first && !(1..=3).contains(&group_len)) {
+                return Err(misplaced(index));
+            }
+            if !first && group_len != 3 {
+                return Err(misplaced(index));
+            }
+            group_at = Some(index);
+            group_len = 0;
+        } else {
+            return Err(ParseSignedDecimalError::InvalidCharacter { index, ch });
+        }
+    }
+    if decimal_at.is_none() {
+        if let Some(at) = group_at.filter(|_| group_len != 3) {
+            return Err(misplaced(at));
+        }
+    }
+    if !any_digit {
+        return Err(if c.rest().is_empty() {
+            ParseSignedDecimalError::Empty
+        } else {
+            ParseSignedDecimalError::MissingDigits
+        });
+    }
+    if n.frac_digits < scale {
+        n.frac *= pow10_u64(scale - n.frac_digits);
+    }
+    Ok(n)
+}
+
+/// The unread part `s[lo..hi]` of an input, so errors can report offsets
+/// into the original string.
+struct Cursor<'a> {
+    s: &'a str,
+    lo: usize,
+    hi: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(s: &'a str) -> Self {
+        Self {
+            s,
+            lo: 0,
+            hi: s.len(),
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.s[self.lo..self.hi]
+    }
+
+    /// The unread characters with their byte offsets into the whole input.
+    fn char_indices(&self) -> impl Iterator<Item = (usize, char)> + 'a {
+        let lo = self.lo;
+        self.rest().char_indices().map(move |(i, ch)| (lo + i, ch))
+    }
+
+    fn trim(&mut self) {
+        let rest = self.rest();
+        self.lo += rest.len() - rest.trim_start().len();
+        self.hi -= rest.len() - rest.trim_end().len();
+        self.hi = self.hi.max(self.lo);
+    }
+
+    fn eat_front(&mut self, ch: char) -> bool {
+        self.eat_front_str(ch.encode_utf8(&mut [0; 4]))
+    }
+
+    fn eat_back(&mut self, ch: char) -> bool {
+        self.eat_back_str(ch.encode_utf8(&mut [0; 4]))
+    }
+
+    fn eat_front_str(&mut self, s: &str) -> bool {
+        let found = self.rest().starts_with(s);
+        if found {
+            self.lo += s.len();
+        }
+        found
+    }
+
+    fn eat_back_str(&mut self, s: &str) -> bool {
+        let found = self.rest().ends_with(s);
+        if found {
+            self.hi -= s.len();
+        }
+        found
+    }
+}
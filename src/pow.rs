@@ -0,0 +1,544 @@
+//! Powers, roots, exponentials and logarithms of `SignedDecimalU64<S>`.
+//!
+//! Every result is rounded once with a `round::RoundingMode`. Square roots are
+//! computed exactly in `u128`. Other powers and roots carry intermediates in
+//! wide fixed point with 36 fractional digits and a sticky "inexact" flag:
+//! results that are representable, or exactly halfway between two
+//! representable values, are always detected, and all other results are
+//! rounded from an approximation whose error is far below one unit in the last
+//! place.
+//!
+//! `exp`, `ln`, `log10` and `pow` sum their series in the same fixed point,
+//! using only integer arithmetic, so results are identical on every platform.
+//! Results that are rational are computed exactly instead: `exp(0)`, `ln(1)`,
+//! `log10` of powers of ten, and `x^(p/q)` whenever `x` is a perfect `q`-th
+//! power (including every integral exponent), which goes through an exact
+//! root and repeated squaring. All other results are irrational and never lie
+//! on a rounding boundary. Each method documents the error of its
+//! approximation before the final rounding: the result is correctly rounded
+//! unless the exact value lies closer than that to a rounding boundary, and is
+//! always within one unit in the last place.
+
+use core::cmp::Ordering;
+
+use decimal64::ScaleMetrics;
+
+use crate::error::{MathError, Result};
+use crate::round::{pow10_u128, should_increment, RoundingMode};
+use crate::wide::Uint;
+use crate::{from_unscaled, SignedDecimalU64};
+
+/// Fractional digits of the fixed-point intermediates.
+const WORK_SCALE: u32 = 36;
+/// `10^(WORK_SCALE / 2)`: rescaling divides by this twice.
+const HALF_WORK_FACTOR: u64 = 1_000_000_000_000_000_000;
+
+/// `10^WORK_SCALE`.
+const WORK_ONE: u128 = 1_000_000_000_000_000_000_000_000_000_000_000_000;
+/// `ln 2 · 10^WORK_SCALE`, truncated.
+const LN_2: i128 = 693_147_180_559_945_309_417_232_121_458_176_568;
+/// `ln 10 · 10^WORK_SCALE`, truncated.
+const LN_10: u128 = 2_302_585_092_994_045_684_017_991_454_684_364_207;
+/// `exp` overflows above this exponent at every scale: `ln(2^64) < 45`.
+const EXP_MAX: u128 = 45 * WORK_ONE;
+/// `exp` of anything below minus this is under `10^-43`, which rounds like
+/// any other tiny positive value.
+const EXP_MIN: u128 = 100 * WORK_ONE;
+
+/// Wide enough for the product of two intermediates below `2^64 · 10^36`.
+type Wide = Uint<8>;
+
+impl<S: ScaleMetrics> SignedDecimalU64<S> {
+    /// `self^exp`, rounded with `mode`; see `try_powi`.
+    pub fn checked_powi(self, exp: i32, mode: RoundingMode) -> Option<Self> {
+        self.try_powi(exp, mode).ok()
+    }
+
+    /// `self^exp`, rounded with `mode`. A negative `exp` raises the reciprocal,
+    /// and `0^0` is one.
+    ///
+    /// Returns `DivisionByZero` for zero raised to a negative power, and
+    /// `Overflow` / `NegativeOverflow` if the result is out of range.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{round::RoundingMode, sdec, U4};
+    /// let r = sdec!(U4, "1.05").try_powi(3, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(r.to_string(), "1.1576");
+    /// let r = sdec!(U4, "-2").try_powi(-3, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(r.to_string(), "-0.1250");
+    /// ```
+    pub fn try_powi(self, exp: i32, mode: RoundingMode) -> Result<Self> {
+        self.pow_int(exp.unsigned_abs() as u64, exp < 0, mode)
+    }
+
+    /// `self^n`, or `self^-n` if `reciprocal`, by exact repeated squaring.
+    fn pow_int(self, n: u64, reciprocal: bool, mode: RoundingMode) -> Result<Self> {
+        let negative = self.is_negative() && n % 2 != 0;
+        let mag = self.unscaled() as u128;
+        let one = S::SCALE_FACTOR as u128;
+        let (num, den) = if !reciprocal {
+            (mag, one)
+        } else if mag == 0 {
+            return Err(MathError::DivisionByZero);
+        } else {
+            (one, mag)
+        };
+        let scale = S::SCALE as u32;
+        let limit = unit_limit(scale).ok_or(MathError::Overflow)?;
+        let unscaled = pow_floor(num, den, n, limit)
+            .and_then(|(v, inexact)| round_fixed(v, inexact, scale, negative, mode))
+            .ok_or(MathError::out_of_range(negative))?;
+        Ok(Self::new(negative, from_unscaled(unscaled)))
+    }
+
+    /// Square root, rounded with `mode`; `None` for negative input.
+    pub fn checked_sqrt(self, mode: RoundingMode) -> Option<Self> {
+        self.try_sqrt(mode).ok()
+    }
+
+    /// Square root, rounded with `mode`.
+    ///
+    /// Returns `NegativeNotAllowed` for negative input.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{round::RoundingMode, sdec, U2};
+    /// let r = sdec!(U2, "2").try_sqrt(RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(r.to_string(), "1.41");
+    /// let r = sdec!(U2, "2").try_sqrt(RoundingMode::Ceil).unwrap();
+    /// assert_eq!(r.to_string(), "1.42");
+    /// ```
+    pub fn try_sqrt(self, mode: RoundingMode) -> Result<Self> {
+        if self.is_negative() {
+            return Err(MathError::NegativeNotAllowed);
+        }
+        // sqrt(u / 10^S) in units of 10^-S is sqrt(u · 10^S) < 2^47.
+        let n = self.unscaled() as u128 * S::SCALE_FACTOR as u128;
+        let q = n.isqrt();
+        let rem = n - q * q;
+        // (q + 1/2)^2 = q^2 + q + 1/4 is never an integer, so there are no
+        // ties: the root is above the midpoint exactly when rem > q.
+        let r = match rem {
+            0 => 0,
+            r if r > q => 3,
+            _ => 1,
+        };
+        let q = q + should_increment(q, r, 4, false, mode) as u128;
+        Ok(Self::new(false, from_unscaled(q as u64)))
+    }
+
+    /// `n`-th root, rounded with `mode`; see `try_nth_root`.
+    pub fn checked_nth_root(self, n: u32, mode: RoundingMode) -> Option<Self> {
+        self.try_nth_root(n, mode).ok()
+    }
+
+    /// `n`-th root, rounded with `mode`. Odd roots of negative values are
+    /// negative.
+    ///
+    /// Returns `DivisionByZero` for `n == 0` and `NegativeNotAllowed` for an
+    /// even root of a negative value.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{round::RoundingMode, sdec, U4};
+    /// let r = sdec!(U4, "-27").try_nth_root(3, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(r.to_string(), "-3.0000");
+    /// ```
+    pub fn try_nth_root(self, n: u32, mode: RoundingMode) -> Result<Self> {
+        let negative = self.is_negative();
+        match n {
+            0 => return Err(MathError::DivisionByZero),
+            1 => return Ok(self),
+            2 if !negative => return self.try_sqrt(mode),
+            _ if negative && n % 2 == 0 => return Err(MathError::NegativeNotAllowed),
+            _ => {}
+        }
+        let (lo, r) = root_floor(self.unscaled(), n, S::SCALE as u32)?;
+        let q = lo + should_increment(lo, r, 4, negative, mode) as u128;
+        let q = u64::try_from(q).map_err(|_| MathError::out_of_range(negative))?;
+        Ok(Self::new(negative, from_unscaled(q)))
+    }
+}
+
+impl<S: ScaleMetrics> SignedDecimalU64<S> {
+    /// `e^self`, rounded with `mode`; `None` on overflow.
+    pub fn checked_exp(self, mode: RoundingMode) -> Option<Self> {
+        self.try_exp(mode).ok()
+    }
+
+    /// `e^self`, rounded with `mode`. Returns `Overflow` if the result is out
+    /// of range; large negative inputs round towards zero.
+    ///
+    /// Exact for zero; otherwise the approximation is within `10^-14` units
+    /// in the last place.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{round::RoundingMode, sdec, U8};
+    /// let r = sdec!(U8, "1").try_exp(RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(r.to_string(), "2.71828183");
+    /// ```
+    pub fn try_exp(self, mode: RoundingMode) -> Result<Self> {
+        if self.is_zero() {
+            return Ok(Self::ONE);
+        }
+        let scale = S::SCALE as u32;
+        let t = to_fixed(self.unscaled(), scale);
+        exp_fixed(self.is_negative(), t)
+            .and_then(|v| round_fixed(v, true, scale, false, mode))
+            .map(|q| Self::new(false, from_unscaled(q)))
+            .ok_or(MathError::Overflow)
+    }
+
+    /// Natural logarithm, rounded with `mode`; `None` for zero or negative
+    /// input.
+    pub fn checked_ln(self, mode: RoundingMode) -> Option<Self> {
+        self.try_ln(mode).ok()
+    }
+
+    /// Natural logarithm, rounded with `mode`. Returns `DivisionByZero` for
+    /// zero (the logarithm diverges) and `NegativeNotAllowed` for negative
+    /// input.
+    ///
+    /// Exact for one; otherwise the approximation is within `10^-24` units in
+    /// the last place.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{round::RoundingMode, sdec, U8};
+    /// let r = sdec!(U8, "0.5").try_ln(RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(r.to_string(), "-0.69314718");
+    /// ```
+    pub fn try_ln(self, mode: RoundingMode) -> Result<Self> {
+        self.check_log_domain()?;
+        if self.unscaled() == S::SCALE_FACTOR {
+            return Ok(Self::ZERO);
+        }
+        let scale = S::SCALE as u32;
+        let l = ln_fixed(to_fixed(self.unscaled(), scale));
+        Ok(round_signed(l, scale, mode))
+    }
+
+    /// Base-10 logarithm, rounded with `mode`; `None` for zero or negative
+    /// input.
+    pub fn checked_log10(self, mode: RoundingMode) -> Option<Self> {
+        self.try_log10(mode).ok()
+    }
+
+    /// Base-10 logarithm, rounded with `mode`, with the same errors as
+    /// `try_ln`.
+    ///
+    /// Exact for powers of ten; otherwise the approximation is within
+    /// `10^-24` units in the last place.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{round::RoundingMode, sdec, U4};
+    /// let r = sdec!(U4, "0.001").try_log10(RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(r.to_string(), "-3.0000");
+    /// let r = sdec!(U4, "2").try_log10(RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(r.to_string(), "0.3010");
+    /// ```
+    pub fn try_log10(self, mode: RoundingMode) -> Result<Self> {
+        self.check_log_domain()?;
+        let scale = S::SCALE as u32;
+        let mut digits = 0;
+        let mut rest = self.unscaled();
+        while rest % 10 == 0 {
+            rest /= 10;
+            digits += 1;
+        }
+        if rest == 1 {
+            let exponent = digits as i64 - scale as i64;
+            let mag = exponent.unsigned_abs() * S::SCALE_FACTOR;
+            return Ok(Self::new(exponent < 0, from_unscaled(mag)));
+        }
+        let l = ln_fixed(to_fixed(self.unscaled(), scale));
+        let (q, _) = Wide::from_u128(l.unsigned_abs())
+            .checked_mul(Wide::from_u128(WORK_ONE))
+            .unwrap()
+            .div_rem_u128(LN_10);
+        let mag = q.to_u128().unwrap() as i128;
+        Ok(round_signed(if l < 0 { -mag } else { mag }, scale, mode))
+    }
+
+    /// `self^exp`, rounded with `mode`; see `try_pow`.
+    pub fn checked_pow(self, exp: Self, mode: RoundingMode) -> Option<Self> {
+        self.try_pow(exp, mode).ok()
+    }
+
+    /// `self^exp`, rounded with `mode`.
+    ///
+    /// With `exp = p / q` in lowest terms, the result is exact before rounding
+    /// whenever `self` is a perfect `q`-th power: integral exponents use
+    /// repeated squaring, and otherwise the exact root is raised to `p`. A
+    /// negative base is allowed when `q` is odd. Any other result is
+    /// irrational and computed as `e^(exp · ln |self|)`, with an
+    /// approximation within `10^-4` units in the last place.
+    ///
+    /// Returns `NegativeNotAllowed` for a negative base with an even `q`,
+    /// `DivisionByZero` for zero raised to a negative power and `Overflow` /
+    /// `NegativeOverflow` if the result is out of range.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{round::RoundingMode, sdec, U2, U8};
+    /// let r = sdec!(U8, "2").try_pow(sdec!(U8, "0.5"), RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(r.to_string(), "1.41421356");
+    /// let r = sdec!(U2, "100").try_pow(sdec!(U2, "1.5"), RoundingMode::Floor).unwrap();
+    /// assert_eq!(r.to_string(), "1000.00");
+    /// ```
+    pub fn try_pow(self, exp: Self, mode: RoundingMode) -> Result<Self> {
+        let one = S::SCALE_FACTOR;
+        let (base, y) = (self.unscaled(), exp.unscaled());
+        if y == 0 || (base == one && !self.is_negative()) {
+            return Ok(Self::ONE);
+        }
+        if y == one && !exp.is_negative() {
+            return Ok(self);
+        }
+        // exp = p / q in lowest terms; q divides 10^S.
+        let g = gcd(y, one);
+        let (p, q) = (y / g, one / g);
+        if q == 1 {
+            return self.pow_int(p, exp.is_negative(), mode);
+        }
+        if self.is_negative() && q % 2 == 0 {
+            return Err(MathError::NegativeNotAllowed);
+        }
+        let scale = S::SCALE as u32;
+        // q <= 10^8.
+        let (root, r) = root_floor(base, q as u32, scale)?;
+        if r == 0 {
+            // The root's denominator, raised to q, divides 10^S, so the root
+            // has at most S decimals; it is at most max(|self|, 1).
+            let root = Self::new(self.is_negative(), from_unscaled(root as u64));
+            return root.pow_int(p, exp.is_negative(), mode);
+        }
+        let negative = self.is_negative() && p % 2 != 0;
+        let l = ln_fixed(to_fixed(base, scale));
+        let (t, _) = Wide::from_u128(l.unsigned_abs())
+            .checked_mul(Wide::from_u128(y as u128))
+            .unwrap()
+            .div_rem_u64(one);
+        // |self| is not a perfect q-th power, so the result is irrational.
+        exp_fixed((l < 0) != exp.is_negative(), t)
+            .and_then(|v| round_fixed(v, true, scale, negative, mode))
+            .map(|q| Self::new(negative, from_unscaled(q)))
+            .ok_or(MathError::out_of_range(negative))
+    }
+
+    fn check_log_domain(&self) -> Result<()> {
+        if self.is_negative() {
+            Err(MathError::NegativeNotAllowed)
+        } else if self.is_zero() {
+            Err(MathError::DivisionByZero)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// ---------- helpers ----------
+
+/// `mag · 10^-scale` in units of `10^-WORK_SCALE`.
+fn to_fixed(mag: u64, scale: u32) -> Wide {
+    Wide::from_u128(mag as u128)
+        .checked_mul(Wide::from_u128(pow10_u128(WORK_SCALE - scale).unwrap()))
+        .unwrap()
+}
+
+/// `e^±t` for `t` in units of `10^-WORK_SCALE`, or `None` if it certainly
+/// doesn't fit in `u64` at any scale.
+fn exp_fixed(negative: bool, t: Wide) -> Option<Wide> {
+    if !negative && t > Wide::from_u128(EXP_MAX) {
+        return None;
+    }
+    if negative && t > Wide::from_u128(EXP_MIN) {
+        return Some(Wide::ZERO);
+    }
+    let t = t.to_u128().unwrap() as i128;
+    let t = if negative { -t } else { t };
+    // e^t = 2^k · e^r with 0 <= r < ln 2.
+    let k = t.div_euclid(LN_2);
+    let r = Wide::from_u128(t.rem_euclid(LN_2) as u128);
+    let mut dropped = false;
+    let (mut sum, mut term) = (Wide::from_u128(WORK_ONE), Wide::from_u128(WORK_ONE));
+    for i in 1.. {
+        term = mul_fixed(term, r, &mut dropped)?.div_rem_u64(i).0;
+        if term.is_zero() {
+            break;
+        }
+        sum = sum.wrapping_add(term);
+    }
+    if k >= 0 {
+        return sum.checked_mul(Wide::from_u128(1 << k));
+    }
+    let mut shift = k.unsigned_abs();
+    while shift > 0 {
+        let step = shift.min(63);
+        sum = sum.div_rem_u64(1 << step).0;
+        shift -= step;
+    }
+    Some(sum)
+}
+
+/// `ln x` for a positive `x`, both in units of `10^-WORK_SCALE`.
+fn ln_fixed(x: Wide) -> i128 {
+    let one = Wide::from_u128(WORK_ONE);
+    let two = Wide::from_u128(2 * WORK_ONE);
+    // x = 2^k · m with 1 <= m < 2.
+    let (mut m, mut k) = (x, 0i128);
+    while m >= two {
+        m = m.div_rem_u64(2).0;
+        k += 1;
+    }
+    while m < one {
+        m = m.wrapping_add(m);
+        k -= 1;
+    }
+    // ln m = 2 atanh(z) = 2 (z + z^3/3 + z^5/5 + ...) with z = (m - 1) / (m + 1) < 1/3.
+    let m = m.to_u128().unwrap();
+    let (z, _) = Wide::from_u128(m - WORK_ONE)
+        .checked_mul(one)
+        .unwrap()
+        .div_rem_u128(m + WORK_ONE);
+    let mut dropped = false;
+    let z2 = mul_fixed(z, z, &mut dropped).unwrap();
+    let (mut sum, mut power) = (z, z);
+    for i in 1.. {
+        power = mul_fixed(power, z2, &mut dropped).unwrap();
+        let term = power.div_rem_u64(2 * i + 1).0;
+        if term.is_zero() {
+            break;
+        }
+        sum = sum.wrapping_add(term);
+    }
+    2 * sum.to_u128().unwrap() as i128 + k * LN_2
+}
+
+/// The largest `q` with `(q / 10^scale)^n <= mag / 10^scale`, and the sticky
+/// remainder code for `should_increment` against a unit of 4: 0 if the root is
+/// exact, 3 above the midpoint and 1 below it.
+fn root_floor(mag: u64, n: u32, scale: u32) -> Result<(u128, u128)> {
+    let one = pow10_u128(scale).ok_or(MathError::Overflow)?;
+    let mag = mag as u128;
+    let limit = unit_limit(scale).ok_or(MathError::Overflow)?;
+    let target = Wide::from_u128(mag)
+        .checked_mul(Wide::from_u128(pow10_u128(WORK_SCALE - scale).unwrap()))
+        .ok_or(MathError::Overflow)?;
+    let cmp = |num: u128, den: u128| pow_cmp(num, den, n as u64, target, limit);
+
+    // The root is at most max(mag, 1).
+    let (mut lo, mut hi) = (0u128, mag.max(one));
+    while lo < hi {
+        let mid = lo + (hi - lo).div_ceil(2);
+        if cmp(mid, one) == Ordering::Greater {
+            hi = mid - 1;
+        } else {
+            lo = mid;
+        }
+    }
+    // As for square roots, (q + 1/2)^n is never exactly `mag`.
+    let r = if cmp(lo, one) == Ordering::Equal {
+        0
+    } else if cmp(2 * lo + 1, 2 * one) == Ordering::Less {
+        3
+    } else {
+        1
+    };
+    Ok((lo, r))
+}
+
+/// Greatest common divisor.
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Round a signed, approximate fixed-point value to `scale` decimal places.
+fn round_signed<S: ScaleMetrics>(v: i128, scale: u32, mode: RoundingMode) -> SignedDecimalU64<S> {
+    let negative = v < 0;
+    // Logarithms of `u64` magnitudes are far below `u64::MAX` at any scale.
+    let q = round_fixed(
+        Wide::from_u128(v.unsigned_abs()),
+        true,
+        scale,
+        negative,
+        mode,
+    )
+    .unwrap();
+    SignedDecimalU64::new(negative, from_unscaled(q))
+}
+
+/// `2^64` units of `10^-scale`, in units of `10^-WORK_SCALE`: the first
+/// magnitude that no longer fits.
+fn unit_limit(scale: u32) -> Option<Wide> {
+    Wide::from_u128(1 << 64).checked_mul(Wide::from_u128(pow10_u128(WORK_SCALE - scale)?))
+}
+
+/// `a · b / 10^WORK_SCALE`, truncated; sets `inexact` if digits were dropped.
+fn mul_fixed(a: Wide, b: Wide, inexact: &mut bool) -> Option<Wide> {
+    let (p, r1) = a.checked_mul(b)?.div_rem_u64(HALF_WORK_FACTOR);
+    let (p, r2) = p.div_rem_u64(HALF_WORK_FACTOR);
+    *inexact |= r1 != 0 || r2 != 0;
+    Some(p)
+}
+
+/// `(num / den)^n` in units of `10^-WORK_SCALE`, truncated, with a flag set if
+/// the truncation dropped anything. Returns `None` if the power is known to
+/// exceed `limit`.
+///
+/// When the reduced denominator of the power divides `10^9`, so does that of
+/// every intermediate, and nothing is truncated. An inexact result is
+/// therefore never a representable value or a midpoint at any scale up to 8.
+fn pow_floor(num: u128, den: u128, mut n: u64, limit: Wide) -> Option<(Wide, bool)> {
+    let one = Wide::from_u128(pow10_u128(WORK_SCALE).unwrap());
+    let (mut base, r) = Wide::from_u128(num).checked_mul(one)?.div_rem_u128(den);
+    let mut inexact = r != 0;
+    // For a base of at least one every intermediate is at most the result,
+    // otherwise every intermediate is at most one.
+    let grows = num >= den;
+    let mut acc = one;
+    loop {
+        if n & 1 == 1 {
+            acc = mul_fixed(acc, base, &mut inexact)?;
+            if grows && acc > limit {
+                return None;
+            }
+        }
+        n >>= 1;
+        if n == 0 {
+            return Some((acc, inexact));
+        }
+        base = mul_fixed(base, base, &mut inexact)?;
+        if grows && base > limit {
+            return None;
+        }
+    }
+}
+
+/// Compare `(num / den)^n` with `target` (in units of `10^-WORK_SCALE`).
+fn pow_cmp(num: u128, den: u128, n: u64, target: Wide, limit: Wide) -> Ordering {
+    match pow_floor(num, den, n, limit) {
+        None => Ordering::Greater,
+        Some((v, false)) => v.cmp(&target),
+        // Truncation only lowered the power, and an inexact power cannot
+        // equal a target of at most 8 decimal places.
+        Some((v, true)) if v >= target => Ordering::Greater,
+        Some(_) => Ordering::Less,
+    }
+}
+
+/// Round a truncated fixed-point magnitude to `scale` decimal places.
+fn round_fixed(
+    v: Wide,
+    inexact: bool,
+    scale: u32,
+    is_negative: bool,
+    mode: RoundingMode,
+) -> Option<u64> {
+    let unit = pow10_u128(WORK_SCALE - scale)?;
+    let (q, r) = v.div_rem_u128(unit);
+    let q = q.to_u128()?;
+    // Dropped digits put the exact value strictly above `r`: doubling `r` and
+    // adding the flag keeps exact midpoints apart from near-midpoints.
+    let inc = should_increment(q, 2 * r + inexact as u128, 2 * unit, is_negative, mode);
+    u64::try_from(q + inc as u128).ok()
+}
@@ -2,8 +2,10 @@
 //
 // - `round_dp(dp, mode)`: round to `dp` fractional digits *while keeping the same scale S*.
 // - `trunc()`, `floor()`, `ceil()` to an integer (i.e., `dp = 0`).
+// - `round_to_increment(step, mode)`: round to a multiple of an arbitrary tick or lot size.
 use decimal64::ScaleMetrics;
 
+use crate::error::{MathError, Result};
 use crate::{from_unscaled, pow10_u64, SignedDecimalU64};
 
 /// Rounding modes supported by this module.
@@ -67,7 +69,7 @@ impl<S: ScaleMetrics> SignedDecimalU64<S> {
             return Some(Self::new(self.is_negative(), from_unscaled::<S>(q * unit)));
         }
 
-        let inc = should_increment(q, r, unit, self.is_negative(), mode);
+        let inc = should_increment(q as u128, r as u128, unit as u128, self.is_negative(), mode);
         let q2 = q.checked_add(inc as u64)?;
         let new_unscaled = q2.checked_mul(unit)?;
         Some(Self::new(
@@ -76,6 +78,24 @@ impl<S: ScaleMetrics> SignedDecimalU64<S> {
         ))
     }
 
+    /// Fallible version of `round_dp` returning `Overflow`/`NegativeOverflow` when
+    /// rounding away from zero leaves the representable range.
+    #[inline]
+    pub fn try_round_dp(self, dp: u32, mode: RoundingMode) -> Result<Self> {
+        let neg = self.is_negative();
+        self.checked_round_dp(dp, mode)
+            .ok_or(MathError::out_of_range(neg))
+    }
+
+    /// Saturating version of `round_dp`: clamps to `MIN`/`MAX` when rounding
+    /// away from zero leaves the representable range.
+    #[inline]
+    pub fn saturating_round_dp(self, dp: u32, mode: RoundingMode) -> Self {
+        let neg = self.is_negative();
+        self.checked_round_dp(dp, mode)
+            .unwrap_or(Self::saturated(neg))
+    }
+
     /// Round to `dp` fractional digits in **the same scale**.
     /// Panics on overflow to mirror the upstream "panic-on-overflow" operator semantics.
     #[inline]
@@ -84,6 +104,51 @@ impl<S: ScaleMetrics> SignedDecimalU64<S> {
             .expect("SignedDecimalU64::round_dp overflow")
     }
 
+    /// Checked version of `round_to_increment`: returns `None` if `step` is
+    /// zero or the rounded value overflows.
+    pub fn checked_round_to_increment(self, step: Self, mode: RoundingMode) -> Option<Self> {
+        let unit = step.unscaled();
+        if unit == 0 {
+            return None;
+        }
+        let u = self.unscaled();
+        let q = u / unit;
+        let r = u % unit;
+        let inc = should_increment(q as u128, r as u128, unit as u128, self.is_negative(), mode);
+        let new_unscaled = q.checked_add(inc as u64)?.checked_mul(unit)?;
+        Some(Self::new(
+            self.is_negative(),
+            from_unscaled::<S>(new_unscaled),
+        ))
+    }
+
+    /// Round to a multiple of `step` (a tick or lot size), in the same scale.
+    /// Only the magnitude of `step` matters; ties in the half modes compare
+    /// the parity of the multiple.
+    /// Panics if `step` is zero or on overflow.
+    ///
+    /// ```rust
+    /// # use signed_decimal64::{round::RoundingMode, sdec, U2};
+    /// let tick = sdec!(U2, "0.05");
+    /// let px = sdec!(U2, "-10.37").round_to_increment(tick, RoundingMode::HalfEven);
+    /// assert_eq!(px.to_string(), "-10.35");
+    /// ```
+    #[inline]
+    pub fn round_to_increment(self, step: Self, mode: RoundingMode) -> Self {
+        self.checked_round_to_increment(step, mode)
+            .expect("SignedDecimalU64::round_to_increment with zero step or overflow")
+    }
+
+    /// Returns `true` if `self` is a whole multiple of `step` (ignoring signs).
+    /// A zero `step` only divides zero.
+    #[inline]
+    pub fn is_multiple_of(&self, step: Self) -> bool {
+        match step.unscaled() {
+            0 => self.unscaled() == 0,
+            step => self.unscaled() % step == 0,
+        }
+    }
+
     /// Convert to another scale `T`, applying rounding if scaling down.
     /// Panics on overflow (use `checked_to_scale` for a fallible version).
     #[inline]
@@ -98,56 +163,54 @@ impl<S: ScaleMetrics> SignedDecimalU64<S> {
         self,
         mode: RoundingMode,
     ) -> Option<SignedDecimalU64<T>> {
-        let s_from = S::SCALE as i32;
-        let s_to = T::SCALE as i32;
-
-        // Same scale: just reinterpret the unscaled integer.
-        if s_from == s_to {
-            return Some(SignedDecimalU64::<T>::new(
-                self.is_negative(),
-                from_unscaled::<T>(self.unscaled()),
-            ));
-        }
-
-        let mag = self.unscaled();
+        let shift = T::SCALE as i32 - S::SCALE as i32;
+        let mag = rescale_round(self.unscaled() as u128, shift, self.is_negative(), mode)?;
+        Some(SignedDecimalU64::<T>::new(
+            self.is_negative(),
+            from_unscaled::<T>(mag),
+        ))
+    }
+}
 
-        if s_to < s_from {
-            // Scaling DOWN: divide by 10^(s_from - s_to) with rounding.
-            let drop = (s_from - s_to) as u32;
-            let unit = pow10_u64(drop);
-            let q = mag / unit;
-            let r = mag % unit;
+impl<S: ScaleMetrics> SignedDecimalU64<S> {
+    /// Fallible conversion to another scale `T` returning `Overflow`/`NegativeOverflow`
+    /// when the rescaled value is out of range.
+    #[inline]
+    pub fn try_to_scale<T: ScaleMetrics>(self, mode: RoundingMode) -> Result<SignedDecimalU64<T>> {
+        let neg = self.is_negative();
+        self.checked_to_scale::<T>(mode)
+            .ok_or(MathError::out_of_range(neg))
+    }
 
-            if r == 0 {
-                return Some(SignedDecimalU64::<T>::new(
-                    self.is_negative(),
-                    from_unscaled::<T>(q),
-                ));
+    /// Exact conversion to another scale `T`.
+    ///
+    /// Returns `PrecisionLoss` if scaling down would discard non-zero digits,
+    /// and `Overflow`/`NegativeOverflow` if the value is out of range for `T`.
+    pub fn try_to_scale_exact<T: ScaleMetrics>(self) -> Result<SignedDecimalU64<T>> {
+        if T::SCALE < S::SCALE {
+            let unit = pow10_u64((S::SCALE - T::SCALE) as u32);
+            if self.unscaled() % unit != 0 {
+                return Err(MathError::PrecisionLoss);
             }
-
-            let inc = should_increment(q, r, unit, self.is_negative(), mode);
-            let q2 = q.checked_add(inc as u64)?;
-            Some(SignedDecimalU64::<T>::new(
-                self.is_negative(),
-                from_unscaled::<T>(q2),
-            ))
-        } else {
-            // Scaling UP: multiply by 10^(s_to - s_from); no rounding needed.
-            let mul = pow10_u64((s_to - s_from) as u32);
-            let new_unscaled = mag.checked_mul(mul)?;
-            Some(SignedDecimalU64::<T>::new(
-                self.is_negative(),
-                from_unscaled::<T>(new_unscaled),
-            ))
         }
+        self.try_to_scale::<T>(RoundingMode::TowardZero)
     }
 }
 
 // ---------- helpers ----------
 
 /// Decide whether to increment the kept digit, given quotient/remainder and mode.
+///
+/// `r` is the discarded remainder and `unit` the divisor it was taken against,
+/// so `r / unit` is the discarded fraction of one unit in the last kept place.
 #[inline]
-fn should_increment(q: u64, r: u64, unit: u64, is_negative: bool, mode: RoundingMode) -> bool {
+pub(crate) fn should_increment(
+    q: u128,
+    r: u128,
+    unit: u128,
+    is_negative: bool,
+    mode: RoundingMode,
+) -> bool {
     if r == 0 {
         return false;
     }
@@ -171,3 +234,47 @@ fn should_increment(q: u64, r: u64, unit: u64, is_negative: bool, mode: Rounding
         }
     }
 }
+
+/// Divide the magnitude `n` by `d`, rounding the quotient with `mode`.
+///
+/// `is_negative` is the sign of the (signed) result and only matters for the
+/// directed modes. Returns `None` if the rounded quotient does not fit in `u64`.
+/// `d` must be non-zero and below `2^127` so the remainder can be doubled.
+#[inline]
+pub(crate) fn div_round(n: u128, d: u128, is_negative: bool, mode: RoundingMode) -> Option<u64> {
+    let q = n / d;
+    let r = n % d;
+    let inc = should_increment(q, r, d, is_negative, mode);
+    let q = q + inc as u128;
+    if q > u64::MAX as u128 {
+        None
+    } else {
+        Some(q as u64)
+    }
+}
+
+/// Rescale the magnitude `n` by `10^shift`, rounding with `mode` when `shift < 0`.
+///
+/// This is the cross-scale core behind `checked_to_scale` and the mixed-scale
+/// arithmetic: callers compute an exact intermediate in `u128` and round once here.
+/// Returns `None` if the result does not fit in `u64`.
+#[inline]
+pub(crate) fn rescale_round(
+    n: u128,
+    shift: i32,
+    is_negative: bool,
+    mode: RoundingMode,
+) -> Option<u64> {
+    if shift >= 0 {
+        let scaled = n.checked_mul(pow10_u128(shift as u32)?)?;
+        u64::try_from(scaled).ok()
+    } else {
+        div_round(n, pow10_u128(shift.unsigned_abs())?, is_negative, mode)
+    }
+}
+
+/// `10^n` as `u128`, or `None` if it doesn't fit.
+#[inline]
+pub(crate) const fn pow10_u128(n: u32) -> Option<u128> {
+    10u128.checked_pow(n)
+}
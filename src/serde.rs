@@ -14,7 +14,7 @@ extern crate alloc;
 use alloc::string::String;
 
 use core::{fmt, marker::PhantomData, str::FromStr};
-use decimal64::{DecimalU64, ScaleMetrics};
+use decimal64::ScaleMetrics;
 
 use crate::serde::alloc::string::ToString;
 use crate::{from_unscaled, SignedDecimalU64};
@@ -137,18 +137,7 @@ fn from_signed_unscaled<S: ScaleMetrics, E: de::Error>(v: i128) -> Result<Signed
 }
 
 fn parse_hr<S: ScaleMetrics, E: de::Error>(s_in: &str) -> Result<SignedDecimalU64<S>, E> {
-    let s = s_in.trim();
-    if s.is_empty() {
-        return Err(E::custom("empty string"));
-    }
-    let (neg, digits) = match s.as_bytes()[0] {
-        b'+' => (false, &s[1..]),
-        b'-' => (true, &s[1..]),
-        _ => (false, s),
-    };
-    let mag = DecimalU64::<S>::from_str(digits)
-        .map_err(|_| E::custom("invalid decimal for this fixed scale"))?;
-    Ok(SignedDecimalU64::new(neg, mag))
+    SignedDecimalU64::from_str(s_in).map_err(E::custom)
 }
 
 #[cfg(test)]
@@ -0,0 +1,168 @@
+//! Exact averages over `SignedDecimalU64<S>` values.
+//!
+//! Every helper accumulates the exact numerator and denominator in wide
+//! integers and rounds once at the end with a `round::RoundingMode`, so the
+//! result does not depend on the order of the inputs.
+
+use decimal64::ScaleMetrics;
+
+use crate::error::{MathError, Result};
+use crate::round::{should_increment, RoundingMode};
+use crate::wide::U256;
+use crate::{from_unscaled, SignedDecimalU64};
+
+/// Arithmetic mean of `values`, rounded with `mode`.
+///
+/// Returns `DivisionByZero` for an empty input.
+///
+/// ```rust
+/// # use signed_decimal64::{round::RoundingMode, sdec, stats, U2};
+/// let xs = [sdec!(U2, "1.00"), sdec!(U2, "2.00"), sdec!(U2, "2.00")];
+/// assert_eq!(stats::mean(xs, RoundingMode::HalfEven).unwrap().to_string(), "1.67");
+/// ```
+pub fn mean<S, I>(values: I, mode: RoundingMode) -> Result<SignedDecimalU64<S>>
+where
+    S: ScaleMetrics,
+    I: IntoIterator<Item = SignedDecimalU64<S>>,
+{
+    // Fewer than 2^63 values of magnitude < 2^64 cannot leave the i128 range.
+    let (total, count) = values
+        .into_iter()
+        .fold((0i128, 0u128), |(total, count), v| {
+            (total.wrapping_add(v.into_unscaled_i128()), count + 1)
+        });
+    if count == 0 {
+        return Err(MathError::DivisionByZero);
+    }
+    round_quotient(
+        total < 0,
+        U256::from_u128(total.unsigned_abs()),
+        count,
+        mode,
+    )
+}
+
+/// Weighted mean `Σ(value·weight) / Σ(weight)` in the scale of the values,
+/// rounded with `mode`.
+///
+/// Values and weights are paired up in order and must have the same length,
+/// otherwise returns `LengthMismatch`. Weights may be negative; returns
+/// `DivisionByZero` if they are empty or sum to zero.
+///
+/// ```rust
+/// # use signed_decimal64::{round::RoundingMode, sdec, stats, U2, U4};
+/// let prices = [sdec!(U2, "10.00"), sdec!(U2, "11.00")];
+/// let weights = [sdec!(U4, "1"), sdec!(U4, "3")];
+/// let m = stats::weighted_mean(prices, weights, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(m.to_string(), "10.75");
+/// ```
+pub fn weighted_mean<S, W, V, Ws>(
+    values: V,
+    weights: Ws,
+    mode: RoundingMode,
+) -> Result<SignedDecimalU64<S>>
+where
+    S: ScaleMetrics,
+    W: ScaleMetrics,
+    V: IntoIterator<Item = SignedDecimalU64<S>>,
+    Ws: IntoIterator<Item = SignedDecimalU64<W>>,
+{
+    weighted(values.into_iter(), weights.into_iter(), mode, false)
+}
+
+/// Volume-weighted average price `Σ(price·|qty|) / Σ|qty|`, rounded HalfEven
+/// into the scale of the prices.
+///
+/// Quantities count by absolute size, so signed fills (sells negative) weigh
+/// the same as buys. Prices and quantities are paired up in order; returns
+/// `LengthMismatch` if their lengths differ, and `DivisionByZero` if there
+/// are no fills or the total quantity is zero.
+///
+/// ```rust
+/// # use signed_decimal64::{sdec, stats, U2, U8};
+/// let prices = [sdec!(U2, "100.00"), sdec!(U2, "101.00")];
+/// let qtys = [sdec!(U8, "0.5"), sdec!(U8, "-1.5")];
+/// assert_eq!(stats::vwap(prices, qtys).unwrap().to_string(), "100.75");
+/// ```
+pub fn vwap<S, Q, P, Qs>(prices: P, qtys: Qs) -> Result<SignedDecimalU64<S>>
+where
+    S: ScaleMetrics,
+    Q: ScaleMetrics,
+    P: IntoIterator<Item = SignedDecimalU64<S>>,
+    Qs: IntoIterator<Item = SignedDecimalU64<Q>>,
+{
+    weighted(
+        prices.into_iter(),
+        qtys.into_iter(),
+        RoundingMode::HalfEven,
+        true,
+    )
+}
+
+/// Shared core of `weighted_mean` and `vwap`.
+///
+/// The products `value_unscaled * weight_unscaled` are exact at scale `S + W`
+/// and the weight sum is exact at scale `W`, so their ratio is at scale `S`.
+fn weighted<S, W, V, Ws>(
+    mut values: V,
+    mut weights: Ws,
+    mode: RoundingMode,
+    abs_weights: bool,
+) -> Result<SignedDecimalU64<S>>
+where
+    S: ScaleMetrics,
+    W: ScaleMetrics,
+    V: Iterator<Item = SignedDecimalU64<S>>,
+    Ws: Iterator<Item = SignedDecimalU64<W>>,
+{
+    // Positive and negative products are summed separately to keep both unsigned.
+    let mut pos = U256::ZERO;
+    let mut neg = U256::ZERO;
+    let mut weight_total: i128 = 0;
+    loop {
+        let (v, w) = match (values.next(), weights.next()) {
+            (Some(v), Some(w)) => (v, w),
+            (None, None) => break,
+            _ => return Err(MathError::LengthMismatch),
+        };
+        let w_neg = w.is_negative() && !abs_weights;
+        let product = U256::from_u128(v.unscaled() as u128 * w.unscaled() as u128);
+        if v.is_negative() ^ w_neg {
+            neg = neg.wrapping_add(product);
+        } else {
+            pos = pos.wrapping_add(product);
+        }
+        let w_signed = w.unscaled() as i128;
+        weight_total = weight_total.wrapping_add(if w_neg { -w_signed } else { w_signed });
+    }
+    if weight_total == 0 {
+        return Err(MathError::DivisionByZero);
+    }
+    let (num_neg, num) = if pos >= neg {
+        (false, pos.wrapping_sub(neg))
+    } else {
+        (true, neg.wrapping_sub(pos))
+    };
+    round_quotient(
+        num_neg ^ (weight_total < 0),
+        num,
+        weight_total.unsigned_abs(),
+        mode,
+    )
+}
+
+/// Round `num / den` with `mode` into a `SignedDecimalU64<S>` with sign `negative`.
+fn round_quotient<S: ScaleMetrics>(
+    negative: bool,
+    num: U256,
+    den: u128,
+    mode: RoundingMode,
+) -> Result<SignedDecimalU64<S>> {
+    let negative = negative && !num.is_zero();
+    let (q, r) = num.div_rem_u128(den);
+    let q = q.to_u128().ok_or(MathError::out_of_range(negative))?;
+    // `den` is below 2^127 (fewer than 2^63 terms), so the remainder can be doubled.
+    let q = q + should_increment(q, r, den, negative, mode) as u128;
+    let mag = u64::try_from(q).map_err(|_| MathError::out_of_range(negative))?;
+    Ok(SignedDecimalU64::new(negative, from_unscaled::<S>(mag)))
+}
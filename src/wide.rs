@@ -0,0 +1,134 @@
+//! Fixed-width unsigned integers wider than `u128` for exact intermediates.
+//!
+//! Crate-private: used where a sum of `u64 * u64` products can exceed `u128`,
+//! and for the fixed-point intermediates of `pow`. Limbs are little-endian `u64`s.
+
+use core::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Uint<const N: usize>(pub(crate) [u64; N]);
+
+/// 256 bits: enough for any sum of fewer than 2^63 `u64 * u64` products.
+pub(crate) type U256 = Uint<4>;
+
+impl<const N: usize> Uint<N> {
+    pub(crate) const ZERO: Self = Uint([0; N]);
+
+    #[inline]
+    pub(crate) const fn from_u128(v: u128) -> Self {
+        let mut limbs = [0; N];
+        limbs[0] = v as u64;
+        limbs[1] = (v >> 64) as u64;
+        Uint(limbs)
+    }
+
+    /// The value as `u128`, or `None` if it doesn't fit.
+    #[inline]
+    pub(crate) fn to_u128(self) -> Option<u128> {
+        if self.0[2..].iter().any(|&l| l != 0) {
+            return None;
+        }
+        Some(self.0[0] as u128 | (self.0[1] as u128) << 64)
+    }
+
+    #[inline]
+    pub(crate) fn is_zero(&self) -> bool {
+        self.0.iter().all(|&l| l == 0)
+    }
+
+    /// Addition modulo `2^(64 N)`.
+    pub(crate) fn wrapping_add(self, rhs: Self) -> Self {
+        let mut out = [0; N];
+        let mut carry = false;
+        for (i, o) in out.iter_mut().enumerate() {
+            let (s, c1) = self.0[i].overflowing_add(rhs.0[i]);
+            let (s, c2) = s.overflowing_add(carry as u64);
+            *o = s;
+            carry = c1 || c2;
+        }
+        Uint(out)
+    }
+
+    /// Subtraction modulo `2^(64 N)`.
+    pub(crate) fn wrapping_sub(self, rhs: Self) -> Self {
+        let mut out = [0; N];
+        let mut borrow = false;
+        for (i, o) in out.iter_mut().enumerate() {
+            let (d, b1) = self.0[i].overflowing_sub(rhs.0[i]);
+            let (d, b2) = d.overflowing_sub(borrow as u64);
+            *o = d;
+            borrow = b1 || b2;
+        }
+        Uint(out)
+    }
+
+    /// Product, or `None` if it doesn't fit in `N` limbs.
+    pub(crate) fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let mut out = [0; N];
+        for (i, &a) in self.0.iter().enumerate() {
+            if a == 0 {
+                continue;
+            }
+            let mut carry: u128 = 0;
+            for (j, &b) in rhs.0.iter().enumerate() {
+                // (2^64 - 1)^2 + 2 (2^64 - 1) = 2^128 - 1, so this cannot overflow.
+                let t = a as u128 * b as u128 + carry;
+                if i + j < N {
+                    let t = t + out[i + j] as u128;
+                    out[i + j] = t as u64;
+                    carry = t >> 64;
+                } else if t != 0 {
+                    return None;
+                }
+            }
+            if carry != 0 {
+                return None;
+            }
+        }
+        Some(Uint(out))
+    }
+
+    /// Quotient and remainder of division by a non-zero `u64` (one limb at a time).
+    pub(crate) fn div_rem_u64(self, d: u64) -> (Self, u64) {
+        debug_assert!(d != 0);
+        let d = d as u128;
+        let mut q = [0; N];
+        let mut rem: u128 = 0;
+        for i in (0..N).rev() {
+            let cur = (rem << 64) | self.0[i] as u128;
+            q[i] = (cur / d) as u64;
+            rem = cur % d;
+        }
+        (Uint(q), rem as u64)
+    }
+
+    /// Quotient and remainder of division by a non-zero `u128` (binary long division).
+    pub(crate) fn div_rem_u128(self, d: u128) -> (Self, u128) {
+        debug_assert!(d != 0);
+        let mut q = [0; N];
+        let mut rem: u128 = 0;
+        for bit in (0..64 * N).rev() {
+            // `carry` is the bit shifted out of `rem`; with it set, the shifted
+            // remainder is at least 2^128 and therefore above `d`.
+            let carry = rem >> 127;
+            rem = (rem << 1) | ((self.0[bit / 64] >> (bit % 64)) & 1) as u128;
+            if carry == 1 || rem >= d {
+                rem = rem.wrapping_sub(d);
+                q[bit / 64] |= 1 << (bit % 64);
+            }
+        }
+        (Uint(q), rem)
+    }
+}
+
+impl<const N: usize> PartialOrd for Uint<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for Uint<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
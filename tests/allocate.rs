@@ -0,0 +1,50 @@
+#![cfg(feature = "alloc")]
+use decimal64::U2;
+use signed_decimal64::{sdec, SignedDecimalU64};
+
+fn shown(parts: &[SignedDecimalU64<U2>]) -> Vec<String> {
+    parts.iter().map(|p| p.to_string()).collect()
+}
+
+#[test]
+fn allocate_preserves_total() {
+    let cash = sdec!(U2, "100.00");
+    let parts = cash.allocate(&[1, 1, 1]);
+    assert_eq!(shown(&parts), ["33.34", "33.33", "33.33"]);
+    assert_eq!(parts.iter().sum::<SignedDecimalU64<U2>>(), cash);
+
+    // Equal remainders: leftover cents go to the earliest tied shares.
+    let parts = sdec!(U2, "0.10").allocate(&[1, 1, 1]);
+    assert_eq!(shown(&parts), ["0.04", "0.03", "0.03"]);
+    let parts = sdec!(U2, "-0.10").allocate(&[1, 1, 1]);
+    assert_eq!(shown(&parts), ["-0.04", "-0.03", "-0.03"]);
+    let parts = sdec!(U2, "0.20").allocate(&[1, 1, 1]);
+    assert_eq!(shown(&parts), ["0.07", "0.07", "0.06"]);
+    // Remainders 0.2, 0.4, 0.4 (in cents): the leftover cent goes to the
+    // first of the two largest.
+    let parts = sdec!(U2, "0.11").allocate(&[1, 2, 2]);
+    assert_eq!(shown(&parts), ["0.02", "0.05", "0.04"]);
+    let parts = sdec!(U2, "-0.11").allocate(&[1, 2, 2]);
+    assert_eq!(shown(&parts), ["-0.02", "-0.05", "-0.04"]);
+    let parts = sdec!(U2, "-0.05").allocate(&[1, 2, 4]);
+    assert_eq!(shown(&parts), ["-0.01", "-0.01", "-0.03"]);
+
+    let parts = SignedDecimalU64::<U2>::MAX.allocate(&[u64::MAX, 0, u64::MAX]);
+    assert_eq!(parts[1], SignedDecimalU64::ZERO);
+    assert_eq!(
+        parts.iter().map(|p| p.unscaled() as u128).sum::<u128>(),
+        u64::MAX as u128
+    );
+    assert!(cash.checked_allocate(&[]).is_none());
+    assert!(cash.checked_allocate(&[0, 0]).is_none());
+}
+
+#[test]
+fn split_evenly_preserves_total() {
+    let parts = sdec!(U2, "-1.00").split_evenly(3);
+    assert_eq!(shown(&parts), ["-0.34", "-0.33", "-0.33"]);
+    let parts = sdec!(U2, "0.02").split_evenly(4);
+    assert_eq!(shown(&parts), ["0.01", "0.01", "0.00", "0.00"]);
+    assert!(!parts[3].is_negative());
+    assert!(sdec!(U2, "1").checked_split_evenly(0).is_none());
+}
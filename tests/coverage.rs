@@ -111,3 +111,357 @@ fn macro_unscaled_const() {
     assert!(CONST_FEE.is_negative());
     assert_eq!(CONST_FEE.to_string(), "-2.50");
 }
+
+#[test]
+fn mul_div_with_rounding_mode() {
+    use RoundingMode::*;
+    let a = SignedDecimalU64::<U2>::from_str("0.25").unwrap();
+    // exact product 0.025 sits on a tie at U2
+    let b = SignedDecimalU64::<U2>::from_str("0.10").unwrap();
+    let mul = |x: SignedDecimalU64<U2>, mode| x.checked_mul_round(b, mode).unwrap().to_string();
+    assert_eq!(mul(a, HalfEven), "0.02");
+    assert_eq!(mul(a, HalfUp), "0.03");
+    assert_eq!(mul(a, HalfDown), "0.02");
+    assert_eq!(mul(-a, HalfUp), "-0.03");
+    assert_eq!(mul(-a, Floor), "-0.03");
+    assert_eq!(mul(-a, Ceil), "-0.02");
+
+    let two = SignedDecimalU64::<U2>::from_str("-2").unwrap();
+    let three = SignedDecimalU64::<U2>::from_str("3").unwrap();
+    let div = |mode| two.checked_div_round(three, mode).unwrap().to_string();
+    assert_eq!(div(TowardZero), "-0.66");
+    assert_eq!(div(HalfEven), "-0.67");
+    assert_eq!(div(Ceil), "-0.66");
+    assert_eq!(div(Floor), "-0.67");
+    assert!(two
+        .checked_div_round(SignedDecimalU64::<U2>::ZERO, HalfEven)
+        .is_none());
+}
+
+#[test]
+fn try_arithmetic_reports_the_failure() {
+    use signed_decimal64::error::MathError;
+    let max = SignedDecimalU64::<U2>::new(false, DecimalU64::<U2>::from_raw(u64::MAX));
+    let min = -max;
+    let one = SignedDecimalU64::<U2>::ONE;
+    assert_eq!(max.try_add(one), Err(MathError::Overflow));
+    assert_eq!(min.try_sub(one), Err(MathError::NegativeOverflow));
+    assert_eq!(max.try_mul(-max), Err(MathError::NegativeOverflow));
+    assert_eq!(
+        one.try_div(SignedDecimalU64::ZERO),
+        Err(MathError::DivisionByZero)
+    );
+    assert_eq!(
+        min.try_round_dp(0, RoundingMode::Floor),
+        Err(MathError::NegativeOverflow)
+    );
+    assert_eq!(
+        max.try_to_scale::<U3>(RoundingMode::HalfUp),
+        Err(MathError::Overflow)
+    );
+    assert_eq!(one.try_add(one).unwrap().to_string(), "2.00");
+
+    let x = SignedDecimalU64::<U3>::from_str("-1.230").unwrap();
+    assert_eq!(x.try_to_scale_exact::<U2>().unwrap().to_string(), "-1.23");
+    let y = SignedDecimalU64::<U3>::from_str("-1.234").unwrap();
+    assert_eq!(y.try_to_scale_exact::<U2>(), Err(MathError::PrecisionLoss));
+
+    assert_eq!(
+        SignedDecimalU64::<U0>::try_from(-(u64::MAX as i128) - 1),
+        Err(MathError::NegativeOverflow)
+    );
+}
+
+#[test]
+fn saturating_arithmetic_clamps() {
+    type D = SignedDecimalU64<U2>;
+    let one = D::ONE;
+    assert_eq!(D::MAX.unscaled(), u64::MAX);
+    assert_eq!(D::MIN, -D::MAX);
+    assert_eq!(D::EPSILON.to_string(), "0.01");
+
+    assert_eq!(D::MAX.saturating_add(one), D::MAX);
+    assert_eq!(D::MIN.saturating_sub(one), D::MIN);
+    assert_eq!(D::MIN.saturating_add(one), D::MIN + one);
+    assert_eq!(D::MAX.saturating_mul(-D::MAX), D::MIN);
+    assert_eq!(D::MIN.saturating_div(D::EPSILON), D::MIN);
+    assert_eq!(D::MAX.saturating_div(-D::EPSILON), D::MIN);
+    assert_eq!(one.saturating_mul(one), one);
+    assert_eq!(D::MAX.saturating_round_dp(0, RoundingMode::Ceil), D::MAX);
+    assert_eq!(D::MIN.saturating_round_dp(0, RoundingMode::Floor), D::MIN);
+    assert!(std::panic::catch_unwind(|| one.saturating_div(D::ZERO)).is_err());
+}
+
+#[test]
+fn wrapping_and_overflowing_arithmetic() {
+    type D = SignedDecimalU64<U0>;
+    let one = D::ONE;
+    let two = D::from_str("2").unwrap();
+
+    assert_eq!(D::MAX.overflowing_add(one), (D::ZERO, true));
+    assert!(!D::MAX.overflowing_add(one).0.is_negative());
+    assert_eq!(D::MAX.overflowing_add(two), (one, true));
+    assert_eq!(D::MIN.overflowing_sub(two), (-one, true));
+    assert_eq!(D::MIN.wrapping_sub(one), D::ZERO);
+    assert_eq!(D::MAX.overflowing_add(-one), (D::MAX - one, false));
+
+    // u64::MAX * 2 = 2^65 - 2 -> wraps to 2^64 - 2, sign of the exact result kept.
+    assert_eq!(D::MAX.overflowing_mul(-two), (-(D::MAX - one), true));
+    assert_eq!(two.wrapping_mul(-two).to_string(), "-4");
+    assert_eq!(two.overflowing_mul(two), (two + two, false));
+
+    // The magnitude wraps after scaling at non-zero scales too.
+    let x = SignedDecimalU64::<U2>::MAX;
+    let y = SignedDecimalU64::<U2>::from_str("-2.00").unwrap();
+    let (w, overflow) = x.overflowing_mul(y);
+    assert!(overflow && w.is_negative());
+    assert_eq!(w.unscaled(), u64::MAX.wrapping_mul(2));
+}
+
+#[test]
+fn scale_aware_constants() {
+    assert_eq!(SignedDecimalU64::<U2>::NEG_ONE.to_string(), "-1.00");
+    assert_eq!(SignedDecimalU64::<U3>::TWO.to_string(), "2.000");
+    assert_eq!(SignedDecimalU64::<U1>::TEN.to_string(), "10.0");
+    assert_eq!(SignedDecimalU64::<U2>::ONE_HUNDRED.to_string(), "100.00");
+    assert_eq!(SignedDecimalU64::<U3>::ulp().to_string(), "0.001");
+    assert_eq!(SignedDecimalU64::<U0>::ulp(), SignedDecimalU64::<U0>::ONE);
+    assert_eq!(
+        SignedDecimalU64::<U0>::max_integer_part(),
+        18_446_744_073_709_551_615
+    );
+    assert_eq!(
+        SignedDecimalU64::<U3>::max_integer_part(),
+        18_446_744_073_709_551
+    );
+    assert_eq!(
+        SignedDecimalU64::<U3>::MIN.to_string(),
+        "-18446744073709551.615"
+    );
+    assert_eq!(
+        SignedDecimalU64::<U2>::NEG_ONE * SignedDecimalU64::<U2>::NEG_ONE,
+        SignedDecimalU64::<U2>::ONE
+    );
+    assert_eq!(
+        SignedDecimalU64::<U0>::MAX,
+        SignedDecimalU64::<U0>::new(false, DecimalU64::<U0>::from_raw(u64::MAX))
+    );
+    assert_eq!(
+        SignedDecimalU64::<U3>::MIN,
+        SignedDecimalU64::<U3>::new(true, DecimalU64::<U3>::from_raw(u64::MAX))
+    );
+    assert_eq!(SignedDecimalU64::<U2>::MIN, -SignedDecimalU64::<U2>::MAX);
+}
+
+#[test]
+fn remainder_and_euclidean_division() {
+    type D = SignedDecimalU64<U2>;
+    let p = |s: &str| D::from_str(s).unwrap();
+    let lot = p("2.50");
+
+    // `%` takes the sign of the dividend, like Rust integers.
+    assert_eq!((p("7.60") % lot).to_string(), "0.10");
+    assert_eq!((p("-7.60") % lot).to_string(), "-0.10");
+    assert_eq!((p("7.60") % -lot).to_string(), "0.10");
+    let mut x = p("-7.60");
+    x %= lot;
+    assert_eq!(x, p("-0.10"));
+    assert!(p("1").checked_rem(D::ZERO).is_none());
+
+    for (a, b) in [
+        ("7.60", "2.50"),
+        ("-7.60", "2.50"),
+        ("7.60", "-2.50"),
+        ("-7.60", "-2.50"),
+    ] {
+        let (a, b) = (p(a), p(b));
+        let q = a.div_euclid(b);
+        let r = a.rem_euclid(b);
+        assert!(!r.is_negative() && r < b.abs());
+        assert_eq!(q.unscaled() % 100, 0);
+        assert_eq!(b * q + r, a);
+    }
+    assert_eq!(p("-7.60").div_euclid(lot).to_string(), "-4.00");
+    assert_eq!(p("-7.60").div_euclid(-lot).to_string(), "4.00");
+    assert_eq!(p("-7.60").rem_euclid(-lot).to_string(), "2.40");
+    assert!(SignedDecimalU64::<U2>::MAX
+        .checked_div_euclid(D::EPSILON)
+        .is_none());
+
+    assert_eq!(p("7.60").div_floor(lot), 3);
+    assert_eq!(p("-7.60").div_floor(lot), -4);
+    assert_eq!(p("-7.50").div_floor(lot), -3);
+    assert_eq!(p("7.60").div_floor(-lot), -4);
+    assert_eq!(D::MAX.checked_div_floor(D::EPSILON), None);
+    assert_eq!(D::ONE.checked_div_floor(D::ZERO), None);
+}
+
+#[test]
+fn fallible_iterator_sums() {
+    use signed_decimal64::error::MathError;
+    use signed_decimal64::prelude::SignedDecimalIterExt;
+    type D = SignedDecimalU64<U0>;
+    let vals = [sdec!(U0, 1), sdec!(U0, -2), sdec!(U0, 3)];
+    assert_eq!(vals.iter().copied().checked_sum(), Some(sdec!(U0, 2)));
+    assert_eq!(vals.iter().copied().try_sum(), Ok(sdec!(U0, 2)));
+    assert_eq!(vals.iter().copied().checked_product(), Some(sdec!(U0, -6)));
+    assert_eq!(vals.iter().sum::<Option<D>>(), Some(sdec!(U0, 2)));
+
+    let over = [D::MAX, D::ONE, -D::ONE];
+    assert_eq!(over.iter().copied().checked_sum(), None);
+    assert_eq!(over.iter().sum::<Option<D>>(), None);
+    assert_eq!(over.into_iter().sum::<Option<D>>(), None);
+    assert_eq!(over.iter().copied().try_sum(), Err(MathError::Overflow));
+    let under = [D::MIN, -D::ONE];
+    assert_eq!(
+        under.iter().copied().try_sum(),
+        Err(MathError::NegativeOverflow)
+    );
+    assert_eq!(under.iter().copied().saturating_sum(), D::MIN);
+    assert_eq!(over.iter().copied().saturating_sum(), D::MAX - D::ONE);
+    assert_eq!([D::MAX, D::TWO].into_iter().checked_product(), None);
+}
+
+#[test]
+fn accumulator_is_exact_until_finish() {
+    use signed_decimal64::accumulator::SignedDecimalAccumulator;
+    use signed_decimal64::error::MathError;
+    type D = SignedDecimalU64<U3>;
+    let fills = [D::MAX, D::MAX, D::MAX, -D::MAX, -D::MAX];
+
+    let acc: SignedDecimalAccumulator<U3> = fills.iter().sum();
+    assert_eq!(acc.unscaled_total(), D::MAX.into_unscaled_i128());
+    assert_eq!(acc.finish(), Ok(D::MAX));
+    assert_eq!(fills.iter().sum::<Option<D>>(), None);
+
+    let mut left: SignedDecimalAccumulator<U3> = fills[..3].iter().copied().collect();
+    assert_eq!(left.finish(), Err(MathError::Overflow));
+    assert_eq!(left.finish_saturating(), D::MAX);
+    let mut right = SignedDecimalAccumulator::default();
+    right.extend(fills[3..].iter().copied());
+    left.merge(right);
+    left.push(D::from_str("-0.001").unwrap());
+    assert_eq!(left.finish(), Ok(D::MAX - D::EPSILON));
+
+    let parts = [left, right, right];
+    let total: SignedDecimalAccumulator<U3> = parts.into_iter().sum();
+    assert_eq!(total.finish(), Err(MathError::NegativeOverflow));
+    assert_eq!(total.finish_saturating(), D::MIN);
+    assert_eq!(SignedDecimalAccumulator::<U3>::new().finish(), Ok(D::ZERO));
+}
+
+#[test]
+fn integer_conversions_name_their_scale() {
+    use signed_decimal64::error::MathError;
+    type D = SignedDecimalU64<U2>;
+
+    // `TryFrom<integer>` and `try_from_unscaled` take the unscaled value...
+    assert_eq!(D::try_from(150u8).unwrap().to_string(), "1.50");
+    assert_eq!(D::try_from(-150i16).unwrap().to_string(), "-1.50");
+    assert_eq!(D::try_from(-7isize).unwrap().to_string(), "-0.07");
+    assert_eq!(D::try_from(u64::MAX).unwrap(), D::MAX);
+    assert_eq!(D::try_from(u128::MAX), Err(MathError::Overflow));
+    assert_eq!(D::try_from(i128::MIN), Err(MathError::NegativeOverflow));
+    assert_eq!(D::try_from_unscaled(-150), D::try_from(-150i32));
+
+    // ...while `from_int` / `try_from_int` count whole units.
+    assert_eq!(D::from_int(-3).to_string(), "-3.00");
+    assert_eq!(
+        D::try_from_int(-(D::max_integer_part() as i128)),
+        Ok(-D::MAX.trunc())
+    );
+    assert_eq!(
+        D::try_from_int(D::max_integer_part() as i128 + 1),
+        Err(MathError::Overflow)
+    );
+    assert_eq!(D::try_from_int(i128::MIN), Err(MathError::NegativeOverflow));
+
+    let x = sdec!(U2, "-7.50");
+    assert_eq!(x.to_i64_trunc(), Some(-7));
+    assert_eq!(x.to_i64(RoundingMode::HalfEven), Some(-8));
+    assert_eq!(x.to_i64(RoundingMode::Ceil), Some(-7));
+    assert_eq!(SignedDecimalU64::<U0>::MAX.to_i64_trunc(), None);
+    assert_eq!(
+        SignedDecimalU64::<U0>::try_from(i64::MIN)
+            .unwrap()
+            .to_i64_trunc(),
+        Some(i64::MIN)
+    );
+}
+
+#[test]
+fn integer_and_fractional_parts() {
+    let x = sdec!(U2, "-1.25");
+    assert_eq!(x.int_part().to_string(), "-1.00");
+    assert_eq!(x.fract().to_string(), "-0.25");
+    assert_eq!(x.int_part() + x.fract(), x);
+    assert_eq!(x.fract_unscaled(), 25);
+    assert_eq!(x.integer_digits(), 1);
+    assert!(!x.is_integer());
+
+    // No negative zero on either side.
+    let y = sdec!(U2, "-0.50");
+    assert!(!y.int_part().is_negative());
+    assert_eq!(y.fract(), y);
+    let z = sdec!(U2, "-300");
+    assert!(z.is_integer());
+    assert!(!z.fract().is_negative());
+    assert_eq!(z.integer_digits(), 3);
+    assert_eq!(SignedDecimalU64::<U0>::MAX.integer_digits(), 20);
+    assert_eq!(SignedDecimalU64::<U3>::ZERO.integer_digits(), 1);
+}
+
+#[test]
+fn round_to_tick_and_lot() {
+    let tick = sdec!(U2, "0.05");
+    let r = |x: SignedDecimalU64<U2>, mode| x.round_to_increment(tick, mode).to_string();
+    assert_eq!(r(sdec!(U2, "10.37"), RoundingMode::TowardZero), "10.35");
+    assert_eq!(r(sdec!(U2, "10.37"), RoundingMode::Ceil), "10.40");
+    assert_eq!(r(sdec!(U2, "-10.37"), RoundingMode::Floor), "-10.40");
+    assert_eq!(r(sdec!(U2, "-10.37"), RoundingMode::Ceil), "-10.35");
+    // 10.375 is halfway between the ticks 10.35 and 10.40.
+    let tick3 = sdec!(U3, "0.050");
+    let tie = sdec!(U3, "10.375");
+    assert_eq!(
+        tie.round_to_increment(tick3, RoundingMode::HalfEven)
+            .to_string(),
+        "10.400"
+    );
+    assert_eq!(
+        tie.round_to_increment(tick3, RoundingMode::HalfDown)
+            .to_string(),
+        "10.350"
+    );
+    assert_eq!(
+        tie.round_to_increment(-tick3, RoundingMode::HalfUp)
+            .to_string(),
+        "10.400"
+    );
+
+    let lot = sdec!(U0, "100");
+    assert_eq!(
+        sdec!(U0, "-250").round_to_increment(lot, RoundingMode::HalfEven),
+        sdec!(U0, "-200")
+    );
+    assert!(sdec!(U0, "-300").is_multiple_of(lot));
+    assert!(!sdec!(U0, "250").is_multiple_of(lot));
+    assert!(SignedDecimalU64::<U0>::ZERO.is_multiple_of(SignedDecimalU64::ZERO));
+    assert!(!lot.is_multiple_of(SignedDecimalU64::ZERO));
+
+    assert_eq!(
+        lot.checked_round_to_increment(SignedDecimalU64::ZERO, RoundingMode::Ceil),
+        None
+    );
+    let max = SignedDecimalU64::<U0>::MAX;
+    assert_eq!(
+        max.checked_round_to_increment(lot, RoundingMode::Ceil),
+        None
+    );
+    assert_eq!(
+        max.checked_round_to_increment(lot, RoundingMode::Floor)
+            .unwrap()
+            .unscaled()
+            % 100,
+        0
+    );
+}
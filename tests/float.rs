@@ -0,0 +1,71 @@
+use decimal64::{U0, U2, U8};
+use signed_decimal64::error::MathError;
+use signed_decimal64::{round::RoundingMode, sdec, SignedDecimalU64};
+
+#[test]
+fn to_float_is_correctly_rounded() {
+    assert_eq!(sdec!(U8, "0.1").to_f64(), 0.1);
+    assert_eq!(sdec!(U8, "-123.45678901").to_f64(), -123.45678901);
+    assert_eq!(sdec!(U2, "0.10").to_f32(), 0.1f32);
+    // 2^53 + 1 is a tie between two floats and rounds to the even one.
+    let x = SignedDecimalU64::<U0>::try_from((1i64 << 53) + 1).unwrap();
+    assert_eq!(f64::from(x), (1u64 << 53) as f64);
+    assert_eq!(f64::from(SignedDecimalU64::<U0>::MAX), u64::MAX as f64);
+    assert_eq!(f32::from(SignedDecimalU64::<U8>::ZERO), 0.0);
+}
+
+#[test]
+fn from_float_rounds_the_binary_value() {
+    let from = |v: f64, mode| SignedDecimalU64::<U2>::from_f64(v, mode).map(|x| x.to_string());
+    assert_eq!(from(0.125, RoundingMode::HalfEven), Ok("0.12".into()));
+    assert_eq!(from(-0.125, RoundingMode::HalfUp), Ok("-0.13".into()));
+    // 0.1 and 2.675 sit just above and just below their decimal literals.
+    assert_eq!(from(0.1, RoundingMode::Ceil), Ok("0.11".into()));
+    assert_eq!(from(2.675, RoundingMode::HalfUp), Ok("2.67".into()));
+    assert_eq!(from(-0.0, RoundingMode::Floor), Ok("0.00".into()));
+    assert_eq!(
+        from(f64::MIN_POSITIVE, RoundingMode::Ceil),
+        Ok("0.01".into())
+    );
+    assert_eq!(from(-5e-324, RoundingMode::Floor), Ok("-0.01".into()));
+    assert_eq!(
+        from(1e17, RoundingMode::HalfEven),
+        Ok("100000000000000000.00".into())
+    );
+    assert_eq!(from(1e18, RoundingMode::HalfEven), Err(MathError::Overflow));
+    assert_eq!(
+        from(-1e300, RoundingMode::HalfEven),
+        Err(MathError::NegativeOverflow)
+    );
+    assert_eq!(
+        from(f64::NAN, RoundingMode::HalfEven),
+        Err(MathError::NonFinite)
+    );
+    assert_eq!(
+        SignedDecimalU64::<U2>::try_from(f64::NEG_INFINITY),
+        Err(MathError::NonFinite)
+    );
+    assert_eq!(
+        SignedDecimalU64::<U2>::try_from(1.005f32).map(|x| x.to_string()),
+        Ok("1.00".into())
+    );
+
+    assert_eq!(
+        SignedDecimalU64::<U2>::from_f64_exact(-2.75),
+        Ok(sdec!(U2, "-2.75"))
+    );
+    assert_eq!(
+        SignedDecimalU64::<U2>::from_f64_exact(0.3),
+        Err(MathError::PrecisionLoss)
+    );
+    assert_eq!(
+        SignedDecimalU64::<U8>::from_f32_exact(0.00390625),
+        Ok(sdec!(U8, "0.00390625"))
+    );
+    // u64::MAX rounds up to 2^64 as a float, which no longer fits.
+    let x = SignedDecimalU64::<U0>::MAX;
+    assert_eq!(
+        SignedDecimalU64::<U0>::from_f64_exact(x.to_f64()),
+        Err(MathError::Overflow)
+    );
+}
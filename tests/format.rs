@@ -0,0 +1,81 @@
+use decimal64::{U0, U2, U4};
+use signed_decimal64::format::{FormatSpec, NegativeStyle};
+use signed_decimal64::{round::RoundingMode, sdec, SignedDecimalU64};
+
+#[test]
+fn separators_and_negative_styles() {
+    let x = sdec!(U2, "-1234567.89");
+    let us = FormatSpec::new().group_separator(',');
+    let eu = FormatSpec::new()
+        .group_separator('.')
+        .decimal_separator(',');
+    assert_eq!(FormatSpec::new().display(&x).to_string(), "-1234567.89");
+    assert_eq!(us.display(&x).to_string(), "-1,234,567.89");
+    assert_eq!(eu.display(&-x).to_string(), "1.234.567,89");
+    assert_eq!(
+        eu.group_separator('\u{202f}').display(&x).to_string(),
+        "-1\u{202f}234\u{202f}567,89"
+    );
+
+    let books = us.negative_style(NegativeStyle::Parentheses).prefix("$");
+    assert_eq!(books.display(&x).to_string(), "($1,234,567.89)");
+    assert_eq!(books.display(&-x).to_string(), "$1,234,567.89");
+    let trailing = eu
+        .negative_style(NegativeStyle::TrailingMinus)
+        .suffix(" EUR");
+    assert_eq!(
+        trailing.display(&sdec!(U2, "-12.34")).to_string(),
+        "12,34 EUR-"
+    );
+    assert_eq!(
+        us.prefix("$").display(&sdec!(U2, "-0.5")).to_string(),
+        "-$0.50"
+    );
+
+    // Grouping boundaries.
+    for (v, want) in [
+        ("0", "0"),
+        ("999", "999"),
+        ("1000", "1,000"),
+        ("100000", "100,000"),
+    ] {
+        let v: SignedDecimalU64<U0> = v.parse().unwrap();
+        assert_eq!(us.display(&v).to_string(), want);
+    }
+    assert_eq!(
+        us.display(&SignedDecimalU64::<U0>::MAX).to_string(),
+        "18,446,744,073,709,551,615"
+    );
+}
+
+#[test]
+fn fraction_digits_and_padding() {
+    let spec = FormatSpec::new()
+        .min_fraction_digits(2)
+        .max_fraction_digits(3);
+    let f = |v: SignedDecimalU64<U4>| spec.display(&v).to_string();
+    assert_eq!(f(sdec!(U4, "7")), "7.00");
+    assert_eq!(f(sdec!(U4, "7.1200")), "7.12");
+    assert_eq!(f(sdec!(U4, "7.1234")), "7.123");
+    assert_eq!(f(sdec!(U4, "7.1235")), "7.124");
+    assert_eq!(f(sdec!(U4, "-0.0004")), "0.00");
+
+    let whole = FormatSpec::new()
+        .max_fraction_digits(0)
+        .rounding(RoundingMode::Floor);
+    assert_eq!(whole.display(&sdec!(U2, "-2.01")).to_string(), "-3");
+    let wide = FormatSpec::new().min_fraction_digits(4);
+    assert_eq!(wide.display(&sdec!(U0, "5")).to_string(), "5.0000");
+
+    let books = FormatSpec::new()
+        .group_separator(',')
+        .negative_style(NegativeStyle::Parentheses);
+    let x = sdec!(U2, "-1234.5");
+    assert_eq!(format!("[{:>12}]", books.display(&x)), "[  (1,234.50)]");
+    assert_eq!(format!("[{:*<12}]", books.display(&x)), "[(1,234.50)**]");
+
+    // Writes into any `fmt::Write` without going through `Display`.
+    let mut out = String::new();
+    books.write_to(&x, &mut out).unwrap();
+    assert_eq!(out, "(1,234.50)");
+}
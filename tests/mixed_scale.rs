@@ -0,0 +1,63 @@
+use decimal64::{U2, U4, U6, U8};
+use signed_decimal64::{round::RoundingMode, sdec, SignedDecimalU64};
+
+#[test]
+fn checked_mixed_scale_methods() {
+    let price = sdec!(U4, "1.2345");
+    let qty = sdec!(U8, "-3.00000001");
+
+    let sum: SignedDecimalU64<U6> = price
+        .checked_add_into::<U8, U6>(qty, RoundingMode::HalfEven)
+        .unwrap();
+    assert_eq!(sum.to_string(), "-1.765500");
+    let diff = price
+        .checked_sub_into::<U8, U2>(qty, RoundingMode::Floor)
+        .unwrap();
+    assert_eq!(diff.to_string(), "4.23");
+
+    // -3.703500012345 rounded into U6
+    let prod = price
+        .checked_mul_into::<U8, U6>(qty, RoundingMode::TowardZero)
+        .unwrap();
+    assert_eq!(prod.to_string(), "-3.703500");
+    let prod = price
+        .checked_mul_into::<U8, U6>(qty, RoundingMode::Floor)
+        .unwrap();
+    assert_eq!(prod.to_string(), "-3.703501");
+
+    let q = sdec!(U2, "1.00")
+        .checked_div_into::<U4, U8>(sdec!(U4, "3"), RoundingMode::HalfUp)
+        .unwrap();
+    assert_eq!(q.to_string(), "0.33333333");
+    let q = sdec!(U8, "-2")
+        .checked_div_into::<U2, U2>(sdec!(U2, "3"), RoundingMode::HalfUp)
+        .unwrap();
+    assert_eq!(q.to_string(), "-0.67");
+    assert!(sdec!(U2, "1")
+        .checked_div_into::<U4, U4>(SignedDecimalU64::<U4>::ZERO, RoundingMode::HalfUp)
+        .is_none());
+}
+
+#[test]
+fn mixed_scale_overflow_is_detected() {
+    let big = SignedDecimalU64::<U2>::new(false, signed_decimal64::DecimalU64::from_raw(u64::MAX));
+    assert!(big
+        .checked_add_into::<U2, U4>(SignedDecimalU64::<U2>::ZERO, RoundingMode::TowardZero)
+        .is_none());
+    // Exact at U4, but rounding back to U2 brings it in range.
+    let x = big
+        .checked_mul_into::<U4, U2>(sdec!(U4, "0.5"), RoundingMode::HalfEven)
+        .unwrap();
+    assert_eq!(x.unscaled(), u64::MAX / 2 + 1);
+}
+
+#[test]
+fn mixed_scale_operators_use_finer_scale() {
+    let cash = sdec!(U2, "10.25");
+    let qty = sdec!(U8, "0.5");
+    let total: SignedDecimalU64<U8> = cash + qty;
+    assert_eq!(total.to_string(), "10.75000000");
+    assert_eq!((qty - cash).to_string(), "-9.75000000");
+    assert_eq!((cash * -qty).to_string(), "-5.12500000");
+    assert_eq!((qty / cash).to_string(), "0.04878048");
+}
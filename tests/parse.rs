@@ -0,0 +1,212 @@
+use decimal64::{U0, U2};
+use signed_decimal64::error::ParseSignedDecimalError as E;
+use signed_decimal64::format::{FormatSpec, NegativeStyle};
+use signed_decimal64::parse::ParseOptions;
+use signed_decimal64::{sdec, SignedDecimalU64};
+
+type D2 = SignedDecimalU64<U2>;
+
+#[test]
+fn parse_with_reads_formatted_output() {
+    let us = ParseOptions::new()
+        .group_separator(',')
+        .prefix("$")
+        .allow_parentheses(true);
+    let eu = ParseOptions::new()
+        .group_separator('.')
+        .decimal_separator(',')
+        .suffix("EUR")
+        .allow_trailing_minus(true);
+
+    for v in [
+        "-1234567.89",
+        "0.05",
+        "999.00",
+        "-1000.10",
+        "18446744073709551.61",
+    ] {
+        let x: D2 = v.parse().unwrap();
+        let books = FormatSpec::new()
+            .group_separator(',')
+            .prefix("$")
+            .negative_style(NegativeStyle::Parentheses);
+        assert_eq!(D2::parse_with(&books.display(&x).to_string(), &us), Ok(x));
+        let plain = FormatSpec::new().prefix("$").group_separator(',');
+        assert_eq!(D2::parse_with(&plain.display(&x).to_string(), &us), Ok(x));
+        let trailing = FormatSpec::new()
+            .group_separator('.')
+            .decimal_separator(',')
+            .suffix(" EUR")
+            .negative_style(NegativeStyle::TrailingMinus);
+        assert_eq!(
+            D2::parse_with(&trailing.display(&x).to_string(), &eu),
+            Ok(x)
+        );
+    }
+
+    // Lenient spellings: optional grouping and affixes, sign after the prefix.
+    assert_eq!(D2::parse_with("  $-1234.5 ", &us), Ok(sdec!(U2, "-1234.5")));
+    assert_eq!(D2::parse_with("( $ 7 )", &us), Ok(sdec!(U2, "-7")));
+    assert_eq!(D2::parse_with("+1.234,5", &eu), Ok(sdec!(U2, "1234.5")));
+    assert_eq!(D2::parse_with(",5-", &eu), Ok(sdec!(U2, "-0.5")));
+    assert_eq!(D2::parse_with("(0.00)", &us), Ok(D2::ZERO));
+    // The defaults match `FromStr`.
+    for s in ["-12.34", "+5", " 0.1 ", "7."] {
+        assert_eq!(D2::parse_with(s, &ParseOptions::new()), s.parse());
+    }
+}
+
+#[test]
+fn parse_with_reports_what_went_wrong() {
+    let us = ParseOptions::new()
+        .group_separator(',')
+        .prefix("$")
+        .allow_parentheses(true);
+    let p = |s| D2::parse_with(s, &us);
+    assert_eq!(p(""), Err(E::Empty));
+    assert_eq!(p("($)"), Err(E::Empty));
+    assert_eq!(p("12a"), Err(E::InvalidCharacter { index: 2, ch: 'a' }));
+    assert_eq!(
+        p("€5"),
+        Err(E::InvalidCharacter {
+            index: 0, ch: '€'
+        })
+    );
+    assert_eq!(
+        p("1€"),
+        Err(E::InvalidCharacter {
+            index: 1, ch: '€'
+        })
+    );
+    assert_eq!(p("12-"), Err(E::InvalidCharacter { index: 2, ch: '-' }));
+    assert_eq!(p("--1"), Err(E::MultipleSigns));
+    assert_eq!(p("$-+1"), Err(E::MultipleSigns));
+    assert_eq!(p("(-1)"), Err(E::MultipleSigns));
+    assert_eq!(p("(1"), Err(E::UnbalancedParenthesis));
+    assert_eq!(p("1)"), Err(E::UnbalancedParenthesis));
+    assert_eq!(p("1,23"), Err(E::MisplacedSeparator { index: 1 }));
+    assert_eq!(p("1234,567"), Err(E::MisplacedSeparator { index: 4 }));
+    assert_eq!(p("1,2345"), Err(E::MisplacedSeparator { index: 1 }));
+    assert_eq!(p(",123"), Err(E::MisplacedSeparator { index: 0 }));
+    assert_eq!(p("1.000,5"), Err(E::MisplacedSeparator { index: 5 }));
+    assert_eq!(p("1.2.3"), Err(E::MisplacedSeparator { index: 3 }));
+    assert_eq!(
+        p("1.234"),
+        Err(E::TooManyFractionalDigits { max: 2, found: 3 })
+    );
+    assert_eq!(p("184467440737095516.16"), Err(E::Overflow));
+    assert_eq!(p("(.)"), Err(E::MissingDigits));
+    let strict = ParseOptions::new();
+    assert_eq!(
+        D2::parse_with("(1)", &strict),
+        Err(E::InvalidCharacter { index: 0, ch: '(' })
+    );
+    assert_eq!(
+        SignedDecimalU64::<U0>::parse_with("18446744073709551615", &strict),
+        Ok(SignedDecimalU64::<U0>::MAX)
+    );
+    assert_eq!(
+        E::InvalidCharacter { index: 2, ch: 'a' }.to_string(),
+        "invalid character 'a' at byte 2"
+    );
+}
+
+#[test]
+fn from_str_errors_name_the_cause() {
+    let p = |s: &str| s.parse::<D2>();
+    assert_eq!(p(" -"), Err(E::Empty));
+    // `"."` used to parse as zero; a lone separator is not a number.
+    assert_eq!(p("."), Err(E::MissingDigits));
+    assert_eq!(p("-."), Err(E::MissingDigits));
+    assert_eq!(p("1_000"), Err(E::InvalidCharacter { index: 1, ch: '_' }));
+    assert_eq!(p(" 1e3"), Err(E::InvalidCharacter { index: 2, ch: 'e' }));
+    assert_eq!(p("- 1"), Err(E::InvalidCharacter { index: 1, ch: ' ' }));
+    assert_eq!(p("+-1"), Err(E::MultipleSigns));
+    assert_eq!(p("1.2.3"), Err(E::MisplacedSeparator { index: 3 }));
+    assert_eq!(p("1,000"), Err(E::InvalidCharacter { index: 1, ch: ',' }));
+    assert_eq!(
+        p("12.345"),
+        Err(E::TooManyFractionalDigits { max: 2, found: 3 })
+    );
+    assert_eq!(p("184467440737095516.16"), Err(E::Overflow));
+    assert_eq!(p("-184467440737095516.15"), Ok(-D2::MAX));
+    assert_eq!(p(".5"), Ok(sdec!(U2, "0.5")));
+    assert_eq!(p("-0.00"), Ok(D2::ZERO));
+
+    let err: &dyn core::error::Error = &E::TooManyFractionalDigits { max: 2, found: 3 };
+    assert_eq!(err.to_string(), "3 fractional digits, at most 2 allowed");
+}
+
+#[test]
+fn from_str_round_and_exact_handle_extra_digits() {
+    use signed_decimal64::round::RoundingMode::{self, *};
+    let modes = [
+        Floor,
+        Ceil,
+        TowardZero,
+        AwayFromZero,
+        HalfUp,
+        HalfDown,
+        HalfEven,
+    ];
+    let r = |s: &str, m: RoundingMode| D2::from_str_round(s, m).map(|x| x.to_string());
+    let cases: [(&str, [&str; 7]); 7] = [
+        (
+            "1.005",
+            ["1.00", "1.01", "1.00", "1.01", "1.01", "1.00", "1.00"],
+        ),
+        (
+            "-1.015",
+            [
+                "-1.02", "-1.01", "-1.01", "-1.02", "-1.02", "-1.01", "-1.02",
+            ],
+        ),
+        (
+            "1.00500000000000000001",
+            ["1.00", "1.01", "1.00", "1.01", "1.01", "1.01", "1.01"],
+        ),
+        (
+            "-0.0049999",
+            ["-0.01", "0.00", "0.00", "-0.01", "0.00", "0.00", "0.00"],
+        ),
+        ("2.5", ["2.50"; 7]),
+        ("7.12000", ["7.12"; 7]),
+        (
+            "-.0001",
+            ["-0.01", "0.00", "0.00", "-0.01", "0.00", "0.00", "0.00"],
+        ),
+    ];
+    for (s, want) in cases {
+        for (m, w) in modes.iter().zip(want) {
+            assert_eq!(r(s, *m).as_deref(), Ok(w), "{s} {m:?}");
+        }
+    }
+    assert_eq!(
+        D2::from_str_round("184467440737095516.155", HalfUp),
+        Err(E::Overflow)
+    );
+    assert_eq!(
+        D2::from_str_round("184467440737095516.155", HalfDown),
+        Ok(D2::MAX)
+    );
+    assert_eq!(
+        D2::from_str_round("1,5", HalfUp),
+        Err(E::InvalidCharacter { index: 1, ch: ',' })
+    );
+
+    assert_eq!(D2::from_str_exact("-7.1200000"), Ok(sdec!(U2, "-7.12")));
+    assert_eq!(D2::from_str_exact("7.1"), Ok(sdec!(U2, "7.1")));
+    assert_eq!(
+        D2::from_str_exact("7.120001"),
+        Err(E::TooManyFractionalDigits { max: 2, found: 6 })
+    );
+    // The strict path still rejects the zeros.
+    assert_eq!(
+        "7.1200".parse::<D2>(),
+        Err(E::TooManyFractionalDigits { max: 2, found: 4 })
+    );
+    assert_eq!(
+        SignedDecimalU64::<U0>::from_str_round("-2.5", HalfEven),
+        Ok(sdec!(U0, "-2"))
+    );
+}
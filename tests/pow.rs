@@ -0,0 +1,302 @@
+use decimal64::{U0, U2, U4, U8};
+use signed_decimal64::error::MathError;
+use signed_decimal64::{round::RoundingMode, sdec, SignedDecimalU64};
+
+#[test]
+fn powi_rounds_once() {
+    let r = |x: SignedDecimalU64<U2>, e, mode| x.checked_powi(e, mode).unwrap().to_string();
+    // 1.5^2 = 2.25 is representable; 0.5^3 = 0.125 is an exact midpoint.
+    assert_eq!(r(sdec!(U2, "1.5"), 2, RoundingMode::TowardZero), "2.25");
+    assert_eq!(r(sdec!(U2, "0.5"), 3, RoundingMode::HalfEven), "0.12");
+    assert_eq!(r(sdec!(U2, "0.5"), 3, RoundingMode::HalfUp), "0.13");
+    assert_eq!(r(sdec!(U2, "-0.5"), 3, RoundingMode::HalfDown), "-0.12");
+    // 1/3 and 1.01^100 = 2.7048138294215...
+    assert_eq!(r(sdec!(U2, "3"), -1, RoundingMode::Ceil), "0.34");
+    assert_eq!(r(sdec!(U2, "-3"), -1, RoundingMode::Floor), "-0.34");
+    assert_eq!(r(sdec!(U2, "1.01"), 100, RoundingMode::HalfEven), "2.70");
+    let x = sdec!(U8, "1.01").checked_powi(100, RoundingMode::HalfEven);
+    assert_eq!(x.unwrap().to_string(), "2.70481383");
+    // 2^60 and 0.5^-30 are exact, and 0.99^1000 underflows towards zero.
+    let x = sdec!(U0, "2").checked_powi(60, RoundingMode::Floor);
+    assert_eq!(x.unwrap().unscaled(), 1 << 60);
+    let x = sdec!(U8, "0.5").checked_powi(-30, RoundingMode::Floor);
+    assert_eq!(x.unwrap().to_string(), "1073741824.00000000");
+    assert_eq!(r(sdec!(U2, "0.99"), 1000, RoundingMode::Ceil), "0.01");
+    assert_eq!(r(sdec!(U2, "0.99"), 1000, RoundingMode::HalfEven), "0.00");
+    assert_eq!(r(sdec!(U2, "0"), 0, RoundingMode::HalfEven), "1.00");
+
+    let big = sdec!(U2, "-10");
+    assert_eq!(
+        big.try_powi(19, RoundingMode::HalfEven),
+        Err(MathError::NegativeOverflow)
+    );
+    assert_eq!(
+        big.try_powi(18, RoundingMode::HalfEven),
+        Err(MathError::Overflow)
+    );
+    assert_eq!(
+        SignedDecimalU64::<U2>::ZERO.try_powi(-1, RoundingMode::HalfEven),
+        Err(MathError::DivisionByZero)
+    );
+}
+
+#[test]
+fn roots_round_once() {
+    let x = sdec!(U8, "2");
+    assert_eq!(
+        x.checked_sqrt(RoundingMode::HalfEven).unwrap().to_string(),
+        "1.41421356"
+    );
+    assert_eq!(
+        x.checked_sqrt(RoundingMode::Ceil).unwrap().to_string(),
+        "1.41421357"
+    );
+    assert_eq!(
+        sdec!(U2, "0.25").checked_sqrt(RoundingMode::Ceil),
+        Some(sdec!(U2, "0.5"))
+    );
+    assert_eq!(
+        sdec!(U2, "-4").try_sqrt(RoundingMode::HalfEven),
+        Err(MathError::NegativeNotAllowed)
+    );
+
+    // 1.05^(1/365) = 1.000133681...
+    let daily = sdec!(U8, "1.05").checked_nth_root(365, RoundingMode::HalfEven);
+    assert_eq!(daily.unwrap().to_string(), "1.00013368");
+    let r = sdec!(U4, "-2").checked_nth_root(3, RoundingMode::Floor);
+    assert_eq!(r.unwrap().to_string(), "-1.2600");
+    let r = sdec!(U4, "0.001").checked_nth_root(3, RoundingMode::Ceil);
+    assert_eq!(r.unwrap().to_string(), "0.1000");
+    assert_eq!(
+        sdec!(U4, "-2").try_nth_root(4, RoundingMode::HalfEven),
+        Err(MathError::NegativeNotAllowed)
+    );
+    assert_eq!(
+        sdec!(U4, "2").try_nth_root(0, RoundingMode::HalfEven),
+        Err(MathError::DivisionByZero)
+    );
+}
+
+/// Round `f(x)` at U8 and compare with references computed to 70 digits,
+/// in `HalfEven`, `Floor` and `Ceil` order.
+fn check_u8(
+    name: &str,
+    f: impl Fn(SignedDecimalU64<U8>, RoundingMode) -> Option<SignedDecimalU64<U8>>,
+    x: &str,
+    want: [&str; 3],
+) {
+    let x: SignedDecimalU64<U8> = x.parse().unwrap();
+    let modes = [
+        RoundingMode::HalfEven,
+        RoundingMode::Floor,
+        RoundingMode::Ceil,
+    ];
+    for (mode, want) in modes.into_iter().zip(want) {
+        let got = f(x, mode).map(|v| v.to_string());
+        assert_eq!(got.as_deref(), Some(want), "{name}({x}) ({mode:?})");
+    }
+}
+
+#[test]
+fn exp_ln_log10_match_reference_values() {
+    let exp: [(&str, &str, &str, &str); 11] = [
+        ("-100", "0.00000000", "0.00000000", "0.00000001"),
+        ("-20.5", "0.00000000", "0.00000000", "0.00000001"),
+        ("-1", "0.36787944", "0.36787944", "0.36787945"),
+        ("-0.00000001", "0.99999999", "0.99999999", "1.00000000"),
+        ("0.00000001", "1.00000001", "1.00000001", "1.00000002"),
+        ("0.1", "1.10517092", "1.10517091", "1.10517092"),
+        ("0.5", "1.64872127", "1.64872127", "1.64872128"),
+        ("1", "2.71828183", "2.71828182", "2.71828183"),
+        ("2.30258509", "9.99999997", "9.99999997", "9.99999998"),
+        ("10", "22026.46579481", "22026.46579480", "22026.46579481"),
+        (
+            "25.5",
+            "118716009132.16965097",
+            "118716009132.16965096",
+            "118716009132.16965097",
+        ),
+    ];
+    let ln: [(&str, &str, &str, &str); 11] = [
+        ("0.00000001", "-18.42068074", "-18.42068075", "-18.42068074"),
+        ("0.1", "-2.30258509", "-2.30258510", "-2.30258509"),
+        ("0.5", "-0.69314718", "-0.69314719", "-0.69314718"),
+        ("0.99999999", "-0.00000001", "-0.00000002", "-0.00000001"),
+        ("1.00000001", "0.00000001", "0.00000000", "0.00000001"),
+        ("1.5", "0.40546511", "0.40546510", "0.40546511"),
+        ("2", "0.69314718", "0.69314718", "0.69314719"),
+        ("2.71828183", "1.00000000", "1.00000000", "1.00000001"),
+        ("10", "2.30258509", "2.30258509", "2.30258510"),
+        ("123456.789", "11.72364649", "11.72364648", "11.72364649"),
+        (
+            "184467440737.09551615",
+            "25.94073881",
+            "25.94073881",
+            "25.94073882",
+        ),
+    ];
+    let log10: [(&str, &str, &str, &str); 11] = [
+        ("0.00000001", "-8.00000000", "-8.00000000", "-8.00000000"),
+        ("0.1", "-1.00000000", "-1.00000000", "-1.00000000"),
+        ("0.5", "-0.30103000", "-0.30103000", "-0.30102999"),
+        ("0.99999999", "0.00000000", "-0.00000001", "0.00000000"),
+        ("1.00000001", "0.00000000", "0.00000000", "0.00000001"),
+        ("1.5", "0.17609126", "0.17609125", "0.17609126"),
+        ("2", "0.30103000", "0.30102999", "0.30103000"),
+        ("2.71828183", "0.43429448", "0.43429448", "0.43429449"),
+        ("10", "1.00000000", "1.00000000", "1.00000000"),
+        ("123456.789", "5.09151498", "5.09151497", "5.09151498"),
+        (
+            "184467440737.09551615",
+            "11.26591972",
+            "11.26591972",
+            "11.26591973",
+        ),
+    ];
+    for (x, even, floor, ceil) in exp {
+        check_u8("exp", SignedDecimalU64::checked_exp, x, [even, floor, ceil]);
+    }
+    for (x, even, floor, ceil) in ln {
+        check_u8("ln", SignedDecimalU64::checked_ln, x, [even, floor, ceil]);
+    }
+    for (x, even, floor, ceil) in log10 {
+        check_u8(
+            "log10",
+            SignedDecimalU64::checked_log10,
+            x,
+            [even, floor, ceil],
+        );
+    }
+
+    let one = SignedDecimalU64::<U8>::ONE;
+    assert_eq!(
+        SignedDecimalU64::<U8>::ZERO.checked_exp(RoundingMode::Ceil),
+        Some(one)
+    );
+    assert_eq!(
+        one.checked_ln(RoundingMode::Ceil),
+        Some(SignedDecimalU64::ZERO)
+    );
+    assert_eq!(
+        sdec!(U8, "26").try_exp(RoundingMode::Floor),
+        Err(MathError::Overflow)
+    );
+    assert_eq!(
+        SignedDecimalU64::<U8>::ZERO.try_ln(RoundingMode::HalfEven),
+        Err(MathError::DivisionByZero)
+    );
+    assert_eq!(
+        (-one).try_log10(RoundingMode::HalfEven),
+        Err(MathError::NegativeNotAllowed)
+    );
+    assert_eq!(
+        sdec!(U0, "10000000000000000000").checked_log10(RoundingMode::Ceil),
+        Some(sdec!(U0, "19"))
+    );
+}
+
+#[test]
+fn pow_matches_reference_values() {
+    let pow: [(&str, &str, &str, &str, &str); 7] = [
+        ("2", "0.5", "1.41421356", "1.41421356", "1.41421357"),
+        ("1.05", "0.25", "1.01227223", "1.01227223", "1.01227224"),
+        ("10", "-1.5", "0.03162278", "0.03162277", "0.03162278"),
+        ("0.5", "3.3", "0.10153155", "0.10153154", "0.10153155"),
+        (
+            "1.00000001",
+            "1000000.5",
+            "1.01005017",
+            "1.01005017",
+            "1.01005018",
+        ),
+        ("123.456", "-0.75", "0.02700013", "0.02700012", "0.02700013"),
+        ("0.01", "0.01", "0.95499259", "0.95499258", "0.95499259"),
+    ];
+    for (x, y, even, floor, ceil) in pow {
+        let y: SignedDecimalU64<U8> = y.parse().unwrap();
+        check_u8(
+            "pow",
+            |x, mode| x.checked_pow(y, mode),
+            x,
+            [even, floor, ceil],
+        );
+    }
+
+    // Integral exponents are exact and allow a negative base.
+    let r = sdec!(U2, "-0.5").checked_pow(sdec!(U2, "3"), RoundingMode::HalfUp);
+    assert_eq!(r.unwrap().to_string(), "-0.13");
+    assert_eq!(
+        sdec!(U2, "-2").try_pow(sdec!(U2, "0.5"), RoundingMode::HalfEven),
+        Err(MathError::NegativeNotAllowed)
+    );
+    assert_eq!(
+        sdec!(U2, "0").try_pow(sdec!(U2, "-0.5"), RoundingMode::HalfEven),
+        Err(MathError::DivisionByZero)
+    );
+    let r = sdec!(U0, "2").checked_pow(sdec!(U0, "63"), RoundingMode::Floor);
+    assert_eq!(r, Some(sdec!(U0, "9223372036854775808")));
+    let r = sdec!(U2, "2").checked_pow(sdec!(U2, "63.5"), RoundingMode::Floor);
+    assert_eq!(r, None);
+    let r = sdec!(U0, "4").checked_pow(sdec!(U0, "31"), RoundingMode::Floor);
+    assert_eq!(r.map(|r| r.unscaled()), Some(1 << 62));
+    assert_eq!(
+        sdec!(U2, "10").try_pow(sdec!(U2, "20.5"), RoundingMode::HalfEven),
+        Err(MathError::Overflow)
+    );
+}
+
+#[test]
+fn pow_is_exact_for_rational_results() {
+    use RoundingMode::*;
+    let pow = |x: &str, y: &str, mode| {
+        let x: SignedDecimalU64<U8> = x.parse().unwrap();
+        x.try_pow(y.parse().unwrap(), mode).map(|r| r.to_string())
+    };
+    for mode in [Ceil, Floor, AwayFromZero, TowardZero] {
+        assert_eq!(pow("1", "0.5", mode).as_deref(), Ok("1.00000000"));
+        assert_eq!(pow("1", "-7.3", mode).as_deref(), Ok("1.00000000"));
+        assert_eq!(pow("4", "0.5", mode).as_deref(), Ok("2.00000000"));
+        assert_eq!(pow("4", "-1.5", mode).as_deref(), Ok("0.12500000"));
+        assert_eq!(pow("0.0625", "0.75", mode).as_deref(), Ok("0.12500000"));
+        assert_eq!(pow("-32", "0.2", mode).as_deref(), Ok("-2.00000000"));
+        assert_eq!(pow("-32", "0.4", mode).as_deref(), Ok("4.00000000"));
+        assert_eq!(pow("2.5", "0", mode).as_deref(), Ok("1.00000000"));
+        assert_eq!(pow("-2.5", "1", mode).as_deref(), Ok("-2.50000000"));
+    }
+    let r = sdec!(U2, "0.25").try_pow(sdec!(U2, "0.5"), RoundingMode::Ceil);
+    assert_eq!(r.map(|r| r.to_string()).as_deref(), Ok("0.50"));
+    let r = sdec!(U2, "100").try_pow(sdec!(U2, "1.5"), RoundingMode::Floor);
+    assert_eq!(r.map(|r| r.to_string()).as_deref(), Ok("1000.00"));
+
+    // Integral exponents beyond i32 use exact repeated squaring too.
+    let huge = sdec!(U0, "3000000000");
+    assert_eq!(
+        sdec!(U0, "1").try_pow(huge, RoundingMode::Ceil),
+        Ok(sdec!(U0, "1"))
+    );
+    assert_eq!(
+        sdec!(U0, "-1").try_pow(huge, RoundingMode::Ceil),
+        Ok(sdec!(U0, "1"))
+    );
+    assert_eq!(
+        sdec!(U0, "-1").try_pow(huge + sdec!(U0, "1"), RoundingMode::Ceil),
+        Ok(sdec!(U0, "-1"))
+    );
+    assert_eq!(
+        sdec!(U2, "-1.01").try_pow(sdec!(U2, "3000000000"), RoundingMode::Ceil),
+        Err(MathError::Overflow)
+    );
+    let tiny = sdec!(U2, "0.5").try_pow(sdec!(U2, "3000000000"), RoundingMode::Ceil);
+    assert_eq!(tiny.map(|r| r.to_string()).as_deref(), Ok("0.01"));
+    let tiny = sdec!(U2, "-0.5").try_pow(sdec!(U2, "3000000001"), RoundingMode::Ceil);
+    assert_eq!(tiny.map(|r| r.to_string()).as_deref(), Ok("0.00"));
+
+    // Irrational results still round from the approximation.
+    assert_eq!(pow("2", "0.5", Ceil).as_deref(), Ok("1.41421357"));
+    assert_eq!(pow("2", "0.5", Floor).as_deref(), Ok("1.41421356"));
+    assert_eq!(pow("-3", "0.2", Floor).as_deref(), Ok("-1.24573094"));
+    assert_eq!(
+        pow("-4", "0.5", HalfEven),
+        Err(MathError::NegativeNotAllowed)
+    );
+}
@@ -0,0 +1,188 @@
+//! Property checks for the wide-intermediate arithmetic against an exact
+//! `i128` reference. Inputs come from a fixed-seed generator so failures
+//! are reproducible.
+
+use decimal64::{DecimalU64, ScaleMetrics, U0, U2, U4, U8};
+use signed_decimal64::{round::RoundingMode, SignedDecimalU64};
+
+const MODES: [RoundingMode; 7] = [
+    RoundingMode::TowardZero,
+    RoundingMode::AwayFromZero,
+    RoundingMode::Ceil,
+    RoundingMode::Floor,
+    RoundingMode::HalfUp,
+    RoundingMode::HalfDown,
+    RoundingMode::HalfEven,
+];
+
+/// xorshift64*: tiny, deterministic, good enough to spread bits around.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A signed value whose magnitude has a random bit length, so that both
+    /// tiny and near-limit operands show up. Magnitudes stay below 2^62 so the
+    /// exact product still fits the `i128` reference.
+    fn value<S: ScaleMetrics>(&mut self) -> SignedDecimalU64<S> {
+        let bits = self.next() % 63;
+        let mag = self.next() >> (64 - bits.max(1));
+        SignedDecimalU64::new(self.next() & 1 == 1, DecimalU64::from_raw(mag))
+    }
+}
+
+/// Round the exact rational `n / d` (with `d > 0`) to an integer.
+fn round_ref(n: i128, d: i128, mode: RoundingMode) -> i128 {
+    let floor = n.div_euclid(d);
+    let r = n.rem_euclid(d);
+    if r == 0 {
+        return floor;
+    }
+    let ceil = floor + 1;
+    let (toward, away) = if n >= 0 { (floor, ceil) } else { (ceil, floor) };
+    match mode {
+        RoundingMode::TowardZero => toward,
+        RoundingMode::AwayFromZero => away,
+        RoundingMode::Ceil => ceil,
+        RoundingMode::Floor => floor,
+        _ if 2 * r > d => ceil,
+        _ if 2 * r < d => floor,
+        RoundingMode::HalfUp => away,
+        RoundingMode::HalfDown => toward,
+        _ if floor % 2 == 0 => floor,
+        _ => ceil,
+    }
+}
+
+fn fits(v: i128) -> bool {
+    v.unsigned_abs() <= u64::MAX as u128
+}
+
+fn check_scale<S: ScaleMetrics + Copy>(seed: u64) {
+    let mut rng = Rng(seed);
+    let unit = 10i128.pow(S::SCALE as u32);
+    for _ in 0..20_000 {
+        let a = rng.value::<S>();
+        let b = rng.value::<S>();
+        let (ai, bi) = (a.into_unscaled_i128(), b.into_unscaled_i128());
+
+        let sum = ai + bi;
+        let got = a.checked_add(b).map(|x| x.into_unscaled_i128());
+        assert_eq!(got, fits(sum).then_some(sum), "{a} + {b}");
+        let diff = ai - bi;
+        let got = a.checked_sub(b).map(|x| x.into_unscaled_i128());
+        assert_eq!(got, fits(diff).then_some(diff), "{a} - {b}");
+
+        for mode in MODES {
+            let prod = round_ref(ai * bi, unit, mode);
+            let got = a.checked_mul_round(b, mode).map(|x| x.into_unscaled_i128());
+            assert_eq!(got, fits(prod).then_some(prod), "{a} * {b} ({mode:?})");
+
+            if bi == 0 {
+                assert!(a.checked_div_round(b, mode).is_none());
+                continue;
+            }
+            // Put the sign on the numerator so the reference divisor stays positive.
+            let (n, d) = (ai * unit * bi.signum(), bi.abs());
+            let quot = round_ref(n, d, mode);
+            let got = a.checked_div_round(b, mode).map(|x| x.into_unscaled_i128());
+            assert_eq!(got, fits(quot).then_some(quot), "{a} / {b} ({mode:?})");
+        }
+    }
+}
+
+#[test]
+fn wide_arithmetic_matches_i128_reference() {
+    check_scale::<U0>(0x9e37_79b9_7f4a_7c15);
+    check_scale::<U2>(0xdead_beef_cafe_f00d);
+    check_scale::<U4>(0x0123_4567_89ab_cdef);
+    check_scale::<U8>(0x5851_f42d_4c95_7f2d);
+}
+
+#[test]
+fn truncating_mul_div_only_fail_on_real_overflow() {
+    let a = "50000".parse::<SignedDecimalU64<U8>>().unwrap();
+    let b = "2".parse::<SignedDecimalU64<U8>>().unwrap();
+    assert_eq!(a.checked_mul(b).unwrap().to_string(), "100000.00000000");
+
+    // u64::MAX unscaled times 1.0 fits exactly; times 1.00000001 does not.
+    let max = SignedDecimalU64::<U8>::new(true, DecimalU64::from_raw(u64::MAX));
+    assert_eq!(max.checked_mul(SignedDecimalU64::ONE), Some(max));
+    let nudge = "1.00000001".parse::<SignedDecimalU64<U8>>().unwrap();
+    assert!(max.checked_mul(nudge).is_none());
+    assert_eq!(max.checked_div(-SignedDecimalU64::ONE), Some(-max));
+    assert!(max.checked_div(nudge).unwrap().unscaled() < u64::MAX);
+}
+
+/// Largest `q` with `q^n <= v`, by bisection.
+fn iroot_ref(v: i128, n: u32) -> i128 {
+    let (mut lo, mut hi) = (0i128, v.max(1));
+    while lo < hi {
+        let mid = lo + (hi - lo + 1) / 2;
+        match mid.checked_pow(n) {
+            Some(p) if p <= v => lo = mid,
+            _ => hi = mid - 1,
+        }
+    }
+    lo
+}
+
+fn check_pow_scale<S: ScaleMetrics + Copy>(seed: u64) {
+    let mut rng = Rng(seed);
+    let unit = 10i128.pow(S::SCALE as u32);
+    for _ in 0..300 {
+        // Magnitudes below 2^20 keep u^4 · 10^(3 S) and 10^(4 S) inside the reference.
+        let mag = rng.next() >> (44 + rng.next() % 20);
+        let x = SignedDecimalU64::<S>::new(rng.next() & 1 == 1, DecimalU64::from_raw(mag));
+        let xi = x.into_unscaled_i128();
+        for mode in MODES {
+            for e in -3i32..=3 {
+                let n = e.unsigned_abs();
+                let want = if e >= 0 {
+                    Some(round_ref(xi.pow(n) * unit, unit.pow(n), mode))
+                } else if xi == 0 {
+                    None
+                } else {
+                    // Keep the reference divisor positive.
+                    let sign = if n % 2 == 1 { xi.signum() } else { 1 };
+                    Some(round_ref(sign * unit.pow(n + 1), xi.abs().pow(n), mode))
+                };
+                let got = x.checked_powi(e, mode).map(|v| v.into_unscaled_i128());
+                assert_eq!(got, want.filter(|&w| fits(w)), "{x} ^ {e} ({mode:?})");
+            }
+
+            for n in [2u32, 3, 4] {
+                let got = x.checked_nth_root(n, mode).map(|v| v.into_unscaled_i128());
+                if x.is_negative() && n % 2 == 0 {
+                    assert_eq!(got, None);
+                    continue;
+                }
+                // floor(root) in ulps, then whether the root passes q + 1/2.
+                let big = xi.abs() * unit.pow(n - 1);
+                let q = iroot_ref(big, n);
+                let r = if q.pow(n) == big {
+                    0
+                } else if (2 * q + 1).pow(n) < big << n {
+                    3
+                } else {
+                    1
+                };
+                let want = round_ref(xi.signum() * (4 * q + r), 4, mode);
+                assert_eq!(got, Some(want), "root {n} of {x} ({mode:?})");
+            }
+        }
+    }
+}
+
+#[test]
+fn powers_and_roots_match_i128_reference() {
+    check_pow_scale::<U0>(0x2545_f491_4f6c_dd1d);
+    check_pow_scale::<U2>(0x9e37_79b9_7f4a_7c15);
+    check_pow_scale::<U4>(0xdead_beef_cafe_f00d);
+    check_pow_scale::<U8>(0x0123_4567_89ab_cdef);
+}
@@ -0,0 +1,56 @@
+use core::ops::{Add, Div, Mul, Rem, Sub};
+use decimal64::{DecimalU64, U2};
+use signed_decimal64::{sdec, SignedDecimalU64};
+
+/// Generic code written against reference operators only.
+fn combine<T>(a: &T, b: &T) -> [T; 5]
+where
+    for<'x> &'x T: Add<&'x T, Output = T>
+        + Sub<&'x T, Output = T>
+        + Mul<&'x T, Output = T>
+        + Div<&'x T, Output = T>
+        + Rem<&'x T, Output = T>,
+{
+    [a + b, a - b, a * b, a / b, a % b]
+}
+
+#[test]
+fn reference_operator_matrix() {
+    let a = sdec!(U2, "-7.50");
+    let b = sdec!(U2, "2.00");
+    let got = combine(&a, &b).map(|x| x.to_string());
+    assert_eq!(got, ["-5.50", "-9.50", "-15.00", "-3.75", "-1.50"]);
+
+    let (ra, rb) = (&a, &b);
+    assert_eq!(ra + b, a + b);
+    assert_eq!(a - rb, a - b);
+    assert_eq!(ra * b, a * b);
+    assert_eq!(a / rb, a / b);
+    assert_eq!(ra % b, a % b);
+
+    let mut x = a;
+    x += rb;
+    x -= rb;
+    x *= rb;
+    x /= rb;
+    x %= rb;
+    assert_eq!(x, sdec!(U2, "-1.50"));
+}
+
+#[test]
+fn mul_div_with_unsigned_magnitude() {
+    let a = sdec!(U2, "-7.50");
+    let m = DecimalU64::<U2>::from_raw(250);
+    assert_eq!((a * m).to_string(), "-18.75");
+    assert_eq!((a / m).to_string(), "-3.00");
+    let rm = &m;
+    assert_eq!((a * rm).to_string(), "-18.75");
+    assert_eq!((a / rm).to_string(), "-3.00");
+
+    let mut x: SignedDecimalU64<U2> = a;
+    x *= m;
+    x /= rm;
+    x *= rm;
+    x /= m;
+    assert_eq!(x, a);
+}
@@ -0,0 +1,44 @@
+use decimal64::{U2, U8};
+use signed_decimal64::{sdec, SignedDecimalU64};
+
+#[test]
+fn integer_factors_are_exact_and_unscaled() {
+    let price = sdec!(U8, "101.23456789");
+    // 3 as a U8 value would be 300000000 unscaled; as a scalar it stays 3.
+    assert_eq!((price * 3_i64).to_string(), "303.70370367");
+    assert_eq!((price * -3_i32).to_string(), "-303.70370367");
+    assert_eq!((price * 3_u64).to_string(), "303.70370367");
+    assert_eq!((-2_i64 * price).to_string(), "-202.46913578");
+    assert_eq!((2_u64 * &price).to_string(), "202.46913578");
+    let (by_ref, two, neg_four) = (&price, &2_i32, &-4_i32);
+    assert_eq!((by_ref * two).to_string(), "202.46913578");
+
+    assert_eq!((price / 4_i64).to_string(), "25.30864197");
+    assert_eq!((-price / 4_u64).to_string(), "-25.30864197");
+    assert_eq!((by_ref / neg_four).to_string(), "-25.30864197");
+
+    let mut x = sdec!(U2, "1.25");
+    x *= -4_i64;
+    assert_eq!(x.to_string(), "-5.00");
+    x /= 3_u64;
+    assert_eq!(x.to_string(), "-1.66");
+}
+
+#[test]
+fn checked_integer_scalars() {
+    let max = SignedDecimalU64::<U2>::MAX;
+    assert!(max.checked_mul_int(2).is_none());
+    assert_eq!(max.checked_mul_int(-1), Some(SignedDecimalU64::MIN));
+    assert!(max.checked_div_int(0).is_none());
+    assert_eq!(
+        sdec!(U2, "-1.00").checked_div_int(i64::MIN).unwrap(),
+        SignedDecimalU64::ZERO
+    );
+    assert_eq!(
+        sdec!(U2, "0.01")
+            .checked_mul_int(i64::MIN)
+            .unwrap()
+            .unscaled(),
+        1 << 63
+    );
+}
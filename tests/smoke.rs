@@ -6,7 +6,7 @@ use signed_decimal64::SignedDecimalU64;
 fn smoke_add_roundtrip() {
     let a = SignedDecimalU64::<U2>::from_str("12.34").unwrap();
     let b = SignedDecimalU64::<U2>::from_str("-0.34").unwrap();
-    assert_eq!((a.clone() + b.clone()).to_string(), "12.00");
+    assert_eq!((a + b).to_string(), "12.00");
     assert_eq!((a - b).to_string(), "12.68");
 }
 
@@ -22,16 +22,10 @@ fn macro_numeric_literal() {
 fn smoke_rounding() {
     use signed_decimal64::round::RoundingMode;
     let x = SignedDecimalU64::<U4>::from_str("-1.2350").unwrap();
-    assert_eq!(
-        x.clone().round_dp(2, RoundingMode::HalfEven).to_string(),
-        "-1.2400"
-    );
-    assert_eq!(
-        x.clone().round_dp(2, RoundingMode::HalfUp).to_string(),
-        "-1.2400"
-    );
-    assert_eq!(x.clone().ceil().to_string(), "-1.0000");
-    assert_eq!(x.clone().floor().to_string(), "-2.0000");
+    assert_eq!(x.round_dp(2, RoundingMode::HalfEven).to_string(), "-1.2400");
+    assert_eq!(x.round_dp(2, RoundingMode::HalfUp).to_string(), "-1.2400");
+    assert_eq!(x.ceil().to_string(), "-1.0000");
+    assert_eq!(x.floor().to_string(), "-2.0000");
 }
 
 #[test]
@@ -53,3 +47,47 @@ fn unsigned_conversions() {
     });
     assert!(res.is_err());
 }
+
+#[test]
+fn display_honors_formatter_flags() {
+    use signed_decimal64::{sdec, U0};
+
+    let x = sdec!(U4, "12.5000");
+    assert_eq!(format!("{x}"), "12.5000");
+    assert_eq!(format!("{x:.2}"), "12.50");
+    assert_eq!(format!("{x:.6}"), "12.500000");
+    assert_eq!(format!("{x:.0}"), "12");
+    assert_eq!(format!("{:.0}", sdec!(U4, "13.5")), "14");
+    assert_eq!(format!("{:.2}", sdec!(U4, "-0.0049")), "0.00");
+    assert_eq!(format!("{:.2}", sdec!(U4, "-0.0050")), "0.00");
+    assert_eq!(format!("{:.2}", sdec!(U4, "-0.0051")), "-0.01");
+    assert_eq!(format!("{x:+}"), "+12.5000");
+    assert_eq!(
+        format!("{x:>9.1}|{x:<9.1}|{x:^9.1}"),
+        "     12.5|12.5     |  12.5   "
+    );
+    assert_eq!(format!("{:08.2}", -x), "-0012.50");
+    assert_eq!(format!("{:+08.2}", x), "+0012.50");
+    assert_eq!(format!("{:_>6.2}", sdec!(U0, "7")), "__7.00");
+    assert_eq!(
+        format!("{:.0}", SignedDecimalU64::<U4>::MAX),
+        "1844674407370955"
+    );
+
+    assert_eq!(x.trim_trailing_zeros().to_string(), "12.5");
+    assert_eq!(sdec!(U4, "-3").trim_trailing_zeros().to_string(), "-3");
+    assert_eq!(
+        sdec!(U4, "0.0100").trim_trailing_zeros().to_string(),
+        "0.01"
+    );
+    assert_eq!(
+        SignedDecimalU64::<U4>::ZERO
+            .trim_trailing_zeros()
+            .to_string(),
+        "0"
+    );
+    assert_eq!(
+        format!("{:>6.3}", sdec!(U4, "1.2501").trim_trailing_zeros()),
+        "  1.25"
+    );
+}
@@ -0,0 +1,89 @@
+use decimal64::{U0, U2, U8};
+use signed_decimal64::error::MathError;
+use signed_decimal64::{round::RoundingMode, sdec, stats, SignedDecimalU64};
+
+#[test]
+fn mean_rounds_once() {
+    let xs = [sdec!(U2, "-1.00"), sdec!(U2, "-2.00"), sdec!(U2, "-2.00")];
+    assert_eq!(
+        stats::mean(xs, RoundingMode::HalfEven).unwrap().to_string(),
+        "-1.67"
+    );
+    assert_eq!(
+        stats::mean(xs, RoundingMode::Ceil).unwrap().to_string(),
+        "-1.66"
+    );
+    assert_eq!(
+        stats::mean(xs, RoundingMode::Floor).unwrap().to_string(),
+        "-1.67"
+    );
+
+    // The running total exceeds u64 but the mean fits.
+    let max = SignedDecimalU64::<U0>::MAX;
+    assert_eq!(
+        stats::mean([max, max, max], RoundingMode::TowardZero),
+        Ok(max)
+    );
+
+    let empty: [SignedDecimalU64<U2>; 0] = [];
+    assert_eq!(
+        stats::mean(empty, RoundingMode::HalfEven),
+        Err(MathError::DivisionByZero)
+    );
+}
+
+#[test]
+fn weighted_mean_and_vwap() {
+    // Products of near-maximal values overflow u128 when summed.
+    let max = SignedDecimalU64::<U8>::MAX;
+    let values = [max, max, max, -max];
+    let weights = [max, max, max, max];
+    let m = stats::weighted_mean(values, weights, RoundingMode::HalfEven).unwrap();
+    assert_eq!(m.unscaled(), u64::MAX / 2 + 1);
+
+    let prices = [sdec!(U2, "10.00"), sdec!(U2, "10.01")];
+    let weights = [sdec!(U0, 1), sdec!(U0, 1)];
+    let half_even = stats::weighted_mean(prices, weights, RoundingMode::HalfEven).unwrap();
+    assert_eq!(half_even.to_string(), "10.00");
+    let half_up = stats::weighted_mean(prices, weights, RoundingMode::HalfUp).unwrap();
+    assert_eq!(half_up.to_string(), "10.01");
+
+    let offsetting = [sdec!(U0, 1), sdec!(U0, -1)];
+    assert_eq!(
+        stats::weighted_mean(prices, offsetting, RoundingMode::HalfEven),
+        Err(MathError::DivisionByZero)
+    );
+
+    let qtys = [sdec!(U8, "2"), sdec!(U8, "-1")];
+    assert_eq!(stats::vwap(prices, qtys).unwrap().to_string(), "10.00");
+    let qtys = [sdec!(U8, "1"), sdec!(U8, "-3")];
+    assert_eq!(stats::vwap(prices, qtys).unwrap().to_string(), "10.01");
+    let none = [SignedDecimalU64::<U8>::ZERO; 2];
+    assert_eq!(stats::vwap(prices, none), Err(MathError::DivisionByZero));
+}
+
+#[test]
+fn mismatched_lengths_are_rejected() {
+    let prices = [sdec!(U2, "10.00"), sdec!(U2, "10.01")];
+    let one = [sdec!(U8, "1")];
+    let three = [sdec!(U8, "1"); 3];
+    let mode = RoundingMode::HalfEven;
+    assert_eq!(
+        stats::weighted_mean(prices, one, mode),
+        Err(MathError::LengthMismatch)
+    );
+    assert_eq!(
+        stats::weighted_mean(prices, three, mode),
+        Err(MathError::LengthMismatch)
+    );
+    assert_eq!(stats::vwap(prices, one), Err(MathError::LengthMismatch));
+    assert_eq!(stats::vwap(prices, three), Err(MathError::LengthMismatch));
+    let no_prices: [SignedDecimalU64<U2>; 0] = [];
+    assert_eq!(stats::vwap(no_prices, one), Err(MathError::LengthMismatch));
+    assert_eq!(
+        stats::vwap(prices, [sdec!(U8, "1"); 2])
+            .unwrap()
+            .to_string(),
+        "10.00"
+    );
+}